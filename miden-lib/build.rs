@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     env, fs,
     fs::File,
     io::{self, BufRead, BufReader, Write},
@@ -7,8 +8,9 @@ use std::{
 
 use assembly::{
     ast::{AstSerdeOptions, ProgramAst},
-    LibraryNamespace, MaslLibrary, Version,
+    Assembler, LibraryNamespace, MaslLibrary, Version,
 };
+use miden_stdlib::StdLibrary;
 
 // CONSTANTS
 // ================================================================================================
@@ -18,6 +20,8 @@ const ASM_DIR: &str = "asm";
 const ASM_MIDEN_DIR: &str = "miden";
 const ASM_NOTE_SCRIPTS_DIR: &str = "note_scripts";
 const ASM_KERNELS_DIR: &str = "kernels/transaction";
+const KERNEL_ERRORS_FILE: &str = "kernel_errors.rs";
+const NOTE_SCRIPT_ROOTS_FILE: &str = "note_script_roots.rs";
 
 // PRE-PROCESSING
 // ================================================================================================
@@ -25,7 +29,9 @@ const ASM_KERNELS_DIR: &str = "kernels/transaction";
 /// Read and parse the contents from `./asm`.
 /// - Compiles contents of asm/miden directory into a Miden library file (.masl) under
 ///   miden namespace.
-/// - Compiles contents of asm/scripts directory into individual .masb files.
+/// - Compiles contents of asm/scripts directory into individual .masb files, and generates the
+///   MAST roots of the compiled scripts into a Rust source file.
+/// - Generates the registry of errors raised by the MASM code into a Rust source file.
 #[cfg(not(feature = "docs-rs"))]
 fn main() -> io::Result<()> {
     // re-build when the MASM code changes
@@ -45,22 +51,27 @@ fn main() -> io::Result<()> {
     let target_dir = Path::new(&build_dir).join(ASSETS_DIR);
 
     // compile miden library
-    compile_miden_lib(&source_dir, &target_dir)?;
+    let miden_lib = compile_miden_lib(&source_dir, &target_dir)?;
 
     // compile kernel and note scripts
     compile_kernels(&source_dir.join(ASM_KERNELS_DIR), &target_dir.join("kernels"))?;
     compile_note_scripts(
         &source_dir.join(ASM_NOTE_SCRIPTS_DIR),
         &target_dir.join(ASM_NOTE_SCRIPTS_DIR),
+        &note_script_assembler(&source_dir, &miden_lib)?,
+        &Path::new(&build_dir).join(NOTE_SCRIPT_ROOTS_FILE),
     )?;
 
+    // generate the registry of kernel errors
+    generate_kernel_errors(&source_dir, &Path::new(&build_dir).join(KERNEL_ERRORS_FILE))?;
+
     Ok(())
 }
 
 // COMPILE MIDEN LIB
 // ================================================================================================
 
-fn compile_miden_lib(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
+fn compile_miden_lib(source_dir: &Path, target_dir: &Path) -> io::Result<MaslLibrary> {
     let source_dir = source_dir.join(ASM_MIDEN_DIR);
 
     // if this build has the testing flag set, modify the code and reduce the cost of proof-of-work
@@ -94,7 +105,7 @@ fn compile_miden_lib(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
 
     miden_lib.write_to_dir(target_dir)?;
 
-    Ok(())
+    Ok(miden_lib)
 }
 
 fn decrease_pow(line: io::Result<String>) -> io::Result<String> {
@@ -114,11 +125,23 @@ fn decrease_pow(line: io::Result<String>) -> io::Result<String> {
 // COMPILE EXECUTABLE MODULES
 // ================================================================================================
 
-fn compile_note_scripts(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
+/// Compiles the note scripts under `source_dir` into .masb files under `target_dir`, and
+/// generates the MAST roots of the scripts compiled with the provided assembler into
+/// `roots_file`.
+///
+/// The root of each script is declared as a `<NAME>_SCRIPT_ROOT` constant, where `<NAME>` is the
+/// name of the script file.
+fn compile_note_scripts(
+    source_dir: &Path,
+    target_dir: &Path,
+    assembler: &Assembler,
+    roots_file: &Path,
+) -> io::Result<()> {
     if let Err(e) = fs::create_dir_all(target_dir) {
         println!("Failed to create note_scripts directory: {}", e);
     }
 
+    let mut roots = BTreeMap::<String, [u64; 4]>::new();
     for masm_file_path in get_masm_files(source_dir)? {
         // read the MASM file, parse it, and serialize the parsed AST to bytes
         let ast = ProgramAst::parse(&fs::read_to_string(masm_file_path.clone())?)?;
@@ -128,11 +151,43 @@ fn compile_note_scripts(source_dir: &Path, target_dir: &Path) -> io::Result<()>
         let masb_file_name = masm_file_path.file_name().unwrap().to_str().unwrap();
         let mut masb_file_path = target_dir.join(masb_file_name);
 
+        // compile the script to determine its MAST root
+        let program = assembler
+            .compile_ast(&ast)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        let script_name = masm_file_path.file_stem().unwrap().to_str().unwrap();
+        roots.insert(script_name.to_uppercase(), program.hash().into());
+
         // write the binary MASB to the output dir
         masb_file_path.set_extension("masb");
         fs::write(masb_file_path, bytes)?;
     }
-    Ok(())
+
+    let mut source = String::from(
+        "// This file is generated by build.rs from the note scripts in the asm/note_scripts \
+         directory.\n",
+    );
+    for (name, root) in roots.iter() {
+        source.push_str(&format!(
+            "\n/// MAST root of the {name} note script.\nconst {name}_SCRIPT_ROOT: [u64; 4] = \
+             {root:?};\n"
+        ));
+    }
+
+    fs::write(roots_file, source)
+}
+
+/// Returns an assembler which compiles note scripts against the provided Miden library, the
+/// standard library, and the transaction kernel under `source_dir`, i.e., the same assembler as
+/// the one returned by `TransactionKernel::assembler()`.
+fn note_script_assembler(source_dir: &Path, miden_lib: &MaslLibrary) -> io::Result<Assembler> {
+    let kernel = fs::read_to_string(source_dir.join(ASM_KERNELS_DIR).join("api.masm"))?;
+
+    Assembler::default()
+        .with_library(miden_lib)
+        .and_then(|assembler| assembler.with_library(&StdLibrary::default()))
+        .and_then(|assembler| assembler.with_kernel(&kernel))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
 }
 
 // COMPILE KERNELS
@@ -157,6 +212,162 @@ fn compile_kernels(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
     Ok(())
 }
 
+// GENERATE KERNEL ERRORS
+// ================================================================================================
+
+/// An error declared in MASM code via a `const.ERR_<NAME>=<code>` constant.
+struct KernelErrorDef {
+    name: String,
+    message: String,
+}
+
+/// Collects the error constants declared in all MASM files under `source_dir` and generates the
+/// `KernelAssertionError` enum from them into `target_file`.
+///
+/// The message of each error is taken from the comment immediately preceding the constant.
+///
+/// # Errors
+/// Returns an error if:
+/// - Two different error constants share the same error code.
+/// - The same error constant is declared with different error codes.
+/// - An error code is not a valid u32 value.
+fn generate_kernel_errors(source_dir: &Path, target_file: &Path) -> io::Result<()> {
+    let mut errors = BTreeMap::<u32, KernelErrorDef>::new();
+
+    for masm_file_path in get_masm_files_recursive(source_dir)? {
+        let mut comment = Vec::new();
+        for line in fs::read_to_string(masm_file_path)?.lines() {
+            let line = line.trim();
+            if let Some(text) = line.strip_prefix('#').filter(|text| !text.starts_with('!')) {
+                if !text.trim().is_empty() {
+                    comment.push(text.trim().to_string());
+                }
+                continue;
+            }
+
+            if let Some((name, code)) =
+                line.strip_prefix("const.").and_then(|constant| constant.split_once('='))
+            {
+                if name.starts_with("ERR_") {
+                    let code = parse_error_code(code.split_whitespace().next().unwrap_or(code))?;
+                    let error = KernelErrorDef {
+                        name: name.to_string(),
+                        message: comment.join(" "),
+                    };
+
+                    if let Some((other_code, _)) = errors
+                        .iter()
+                        .find(|(other_code, other)| other.name == name && **other_code != code)
+                    {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "error {name} is declared with both {other_code:#010x} and \
+                                 {code:#010x} codes"
+                            ),
+                        ));
+                    }
+
+                    match errors.get(&code) {
+                        Some(existing) if existing.name != error.name => {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                format!(
+                                    "error code {code:#010x} is used by both {} and {}",
+                                    existing.name, error.name
+                                ),
+                            ));
+                        },
+                        Some(_) => (),
+                        None => {
+                            errors.insert(code, error);
+                        },
+                    }
+                }
+            }
+            comment.clear();
+        }
+    }
+
+    let mut variants = String::new();
+    let mut from_code = String::new();
+    let mut names = String::new();
+    let mut messages = String::new();
+    for (code, error) in errors.iter() {
+        let variant = to_variant_name(&error.name);
+        variants.push_str(&format!("    /// {}\n    {variant} = {code:#010x},\n", error.message));
+        from_code.push_str(&format!("            {code:#010x} => Some(Self::{variant}),\n"));
+        names.push_str(&format!("            Self::{variant} => {:?},\n", error.name));
+        messages.push_str(&format!("            Self::{variant} => {:?},\n", error.message));
+    }
+
+    let source = format!(
+        "// This file is generated by build.rs from the error constants declared in the MASM code.
+
+/// Errors which may be raised by failed assertions in the transaction kernel, the Miden library,
+/// and the standard note scripts.
+///
+/// The discriminant of each variant is the error code used in the MASM code.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum KernelAssertionError {{
+{variants}}}
+
+impl KernelAssertionError {{
+    /// Returns the error with the specified code, or None if no error with this code is declared
+    /// in the MASM code.
+    pub fn from_code(code: u32) -> Option<Self> {{
+        match code {{
+{from_code}            _ => None,
+        }}
+    }}
+
+    /// Returns the name of the constant under which this error is declared in the MASM code.
+    pub fn name(&self) -> &'static str {{
+        match self {{
+{names}        }}
+    }}
+
+    /// Returns the human-readable message of this error.
+    pub fn message(&self) -> &'static str {{
+        match self {{
+{messages}        }}
+    }}
+}}
+"
+    );
+
+    fs::write(target_file, source)
+}
+
+/// Parses an error code written either as a hexadecimal or as a decimal u32 value.
+fn parse_error_code(code: &str) -> io::Result<u32> {
+    let result = match code.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => code.parse::<u32>(),
+    };
+    result.map_err(|err| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("invalid error code {code}: {err}"))
+    })
+}
+
+/// Converts the name of an error constant (e.g. `ERR_P2IDR_RECLAIM_HEIGHT_NOT_REACHED`) into the
+/// name of an enum variant (e.g. `P2idrReclaimHeightNotReached`).
+fn to_variant_name(name: &str) -> String {
+    name.trim_start_matches("ERR_")
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => {
+                    first.to_ascii_uppercase().to_string() + &chars.as_str().to_lowercase()
+                },
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -228,6 +439,22 @@ fn get_masm_files<P: AsRef<Path>>(dir_path: P) -> io::Result<Vec<PathBuf>> {
     Ok(files)
 }
 
+/// Returns a vector with paths to all MASM files in the specified directory and its
+/// subdirectories.
+fn get_masm_files_recursive<P: AsRef<Path>>(dir_path: P) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir_path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(get_masm_files_recursive(&path)?);
+        } else if is_masm_file(&path)? {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
 /// Returns true if the provided path resolves to a file with `.masm` extension.
 ///
 /// # Errors
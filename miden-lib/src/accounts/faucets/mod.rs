@@ -5,7 +5,7 @@ use miden_objects::{
         Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
         StorageSlot,
     },
-    assembly::LibraryPath,
+    assembly::{LibraryPath, ModuleAst},
     assets::{AssetVault, TokenSymbol},
     AccountError, Felt, Word, ZERO,
 };
@@ -18,6 +18,18 @@ use super::{AuthScheme, Library, MidenLib, TransactionKernel};
 const MAX_MAX_SUPPLY: u64 = (1 << 63) - 1;
 const MAX_DECIMALS: u8 = 12;
 
+/// The code of a basic fungible faucet which uses an authentication scheme other than
+/// RpoFalcon512. The `{AUTH_MODULE}` and `{AUTH_PROCEDURE}` placeholders are replaced with the
+/// module in `miden::contracts::auth` and the authentication procedure of the scheme.
+const BASIC_FUNGIBLE_FAUCET_CODE_TEMPLATE: &str = "
+use.miden::contracts::faucets::basic_fungible->faucet
+use.miden::contracts::auth::{AUTH_MODULE}
+
+export.faucet::distribute
+export.faucet::burn
+export.{AUTH_MODULE}::{AUTH_PROCEDURE}
+";
+
 /// Creates a new faucet account with basic fungible faucet interface,
 /// account storage type, specified authentication scheme, and provided meta data (token symbol, decimals, max supply).
 ///
@@ -38,21 +50,32 @@ pub fn create_basic_fungible_faucet(
     account_storage_type: AccountStorageType,
     auth_scheme: AuthScheme,
 ) -> Result<(Account, Word), AccountError> {
-    // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
-    // faucet contract, so we can just use the public key as storage slot 0.
-    // TODO: consider using a trait when we have more auth schemes.
-    let auth_data: Word = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
-    };
+    let (auth_module, auth_procedure) = auth_scheme.auth_procedure();
+    let auth_data = auth_scheme.storage_slot_0_data();
 
-    let miden = MidenLib::default();
-    let path = "miden::contracts::faucets::basic_fungible";
-    let faucet_code_ast = miden
-        .get_module_ast(&LibraryPath::new(path).unwrap())
-        .expect("Getting module AST failed");
+    // The basic fungible faucet contract uses RpoFalcon512 authentication by default. For other
+    // authentication schemes, we build a module which re-exports the faucet procedures together
+    // with the authentication procedure of the scheme.
+    let faucet_code_ast = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => {
+            let miden = MidenLib::default();
+            let path = "miden::contracts::faucets::basic_fungible";
+            miden
+                .get_module_ast(&LibraryPath::new(path).unwrap())
+                .expect("Getting module AST failed")
+                .clone()
+        },
+        _ => {
+            let faucet_code = BASIC_FUNGIBLE_FAUCET_CODE_TEMPLATE
+                .replace("{AUTH_MODULE}", auth_module)
+                .replace("{AUTH_PROCEDURE}", auth_procedure);
+            ModuleAst::parse(&faucet_code)
+                .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?
+        },
+    };
 
     let account_assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?;
+    let account_code = AccountCode::new(faucet_code_ast, &account_assembler)?;
 
     // First check that the metadata is valid.
     if decimals > MAX_DECIMALS {
@@ -1,13 +1,18 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType,
-        StorageSlot,
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        StorageMap, StorageSlot,
     },
     assembly::ModuleAst,
     assets::AssetVault,
-    AccountError, Word, ZERO,
+    crypto::dsa::rpo_falcon512,
+    AccountError, Felt, Word, ONE, ZERO,
 };
 
 use super::{AuthScheme, TransactionKernel};
@@ -18,12 +23,14 @@ use super::{AuthScheme, TransactionKernel};
 /// Creates a new account with basic wallet interface, the specified authentication scheme and the account storage type.
 /// Basic wallets can be specified to have either mutable or immutable code.
 ///
-/// The basic wallet interface exposes two procedures:
+/// The basic wallet interface exposes three procedures:
 /// - `receive_asset`, which can be used to add an asset to the account.
 /// - `send_asset`, which can be used to remove an asset from the account and put into a note
 ///    addressed to the specified recipient.
+/// - `move_asset_to_note`, which can be used to remove an asset from the account and add it to a
+///    note previously created via `send_asset`.
 ///
-/// Both methods require authentication. The authentication procedure is defined by the specified
+/// All methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
 /// at slot 0.
 pub fn create_basic_wallet(
@@ -38,18 +45,18 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
-    };
+    let (auth_module, auth_procedure) = auth_scheme.auth_procedure();
+    let storage_slot_0_data = auth_scheme.storage_slot_0_data();
 
     let account_code_string: String = format!(
         "
     use.miden::contracts::wallets::basic->basic_wallet
-    use.miden::contracts::auth::basic
+    use.miden::contracts::auth::{auth_module}
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
-    export.{auth_scheme_procedure}
+    export.basic_wallet::move_asset_to_note
+    export.{auth_module}::{auth_procedure}
 
     "
     );
@@ -79,3 +86,119 @@ pub fn create_basic_wallet(
         account_seed,
     ))
 }
+
+// MULTISIG WALLET
+// ================================================================================================
+
+/// Creates a new account with basic wallet interface controlled by multiple RPO Falcon512 keys,
+/// and the specified account storage type. Multisig wallets can be specified to have either
+/// mutable or immutable code.
+///
+/// The multisig wallet exposes the same procedures as the basic wallet, and the
+/// `auth_tx_rpo_falcon512_multisig` authentication procedure which authenticates a transaction
+/// if it was signed by at least `threshold` of the specified approvers.
+///
+/// The multisig configuration is stored in the account storage at slot 0 as
+/// [threshold, num_approvers, 0, 0]. The public keys of the approvers are stored in a storage map
+/// at slot 1 which maps [0, 0, 0, i] to the public key of the i-th approver.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account type is a faucet account type.
+/// - The list of approvers is empty or contains duplicate public keys.
+/// - The threshold is zero or greater than the number of approvers.
+pub fn create_multisig_wallet(
+    init_seed: [u8; 32],
+    approvers: &[rpo_falcon512::PublicKey],
+    threshold: u32,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Multisig wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let approvers: Vec<Word> = approvers.iter().map(|&pub_key| pub_key.into()).collect();
+    if approvers.is_empty() {
+        return Err(AccountError::MultisigWalletInvalidConfig(
+            "Multisig wallet must have at least one approver".to_string(),
+        ));
+    } else if threshold == 0 || threshold as usize > approvers.len() {
+        return Err(AccountError::MultisigWalletInvalidConfig(format!(
+            "Threshold must be between 1 and {}, but was {threshold}",
+            approvers.len()
+        )));
+    } else if approvers.iter().enumerate().any(|(i, key)| approvers[..i].contains(key)) {
+        return Err(AccountError::MultisigWalletInvalidConfig(
+            "Multisig wallet approvers must be unique".to_string(),
+        ));
+    }
+
+    let account_code_src = "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::multisig
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.multisig::auth_tx_rpo_falcon512_multisig
+
+    ";
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast, &account_assembler)?;
+
+    // We store the multisig configuration and the public keys of the approvers in the account
+    // storage:
+    // - slot 0: [threshold, num_approvers, 0, 0]
+    // - slot 1: map of [0, 0, 0, approver_index] |-> approver public key
+    let config = [Felt::from(threshold), Felt::new(approvers.len() as u64), ZERO, ZERO];
+    let approvers_map = StorageMap::with_entries(
+        approvers
+            .iter()
+            .enumerate()
+            .map(|(i, &pub_key)| ([ZERO, ZERO, ZERO, Felt::new(i as u64)].into(), pub_key)),
+    )?;
+
+    let account_storage = AccountStorage::new(vec![
+        SlotItem {
+            index: 0,
+            slot: StorageSlot::new_value(config),
+        },
+        SlotItem {
+            index: 1,
+            slot: StorageSlot::new_map(approvers_map.root().into()),
+        },
+    ])
+    .and_then(|storage| storage.with_maps(BTreeMap::from([(1, approvers_map)])))?;
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+/// Returns the advice map entry which specifies the approvers who signed a transaction executed
+/// against the provided multisig wallet.
+///
+/// `approvals` must contain one flag per approver of the wallet, with the i-th flag set to true if
+/// the i-th approver signed the transaction. The returned entry must be added to the advice map of
+/// the transaction together with the secret keys of the approvers who signed it.
+pub fn multisig_approvals_advice(wallet: &Account, approvals: &[bool]) -> (Word, Vec<Felt>) {
+    let approvers_root = wallet.storage().get_item(1).into();
+    let flags = approvals.iter().map(|&approved| if approved { ONE } else { ZERO }).collect();
+    (approvers_root, flags)
+}
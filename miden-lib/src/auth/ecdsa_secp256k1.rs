@@ -0,0 +1,306 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "ecdsa")]
+use k256::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    SigningKey, VerifyingKey,
+};
+use miden_objects::{Digest, Felt, Hasher, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The prime modulus of the secp256k1 base field as little-endian 64-bit limbs.
+const BASE_FIELD_MODULUS: U256 =
+    [0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff];
+
+/// The order of the secp256k1 curve (i.e., the modulus of the scalar field) as little-endian
+/// 64-bit limbs.
+const SCALAR_FIELD_MODULUS: U256 =
+    [0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff];
+
+/// A 256-bit unsigned integer represented as little-endian 64-bit limbs.
+type U256 = [u64; 4];
+
+// PUBLIC KEY
+// ================================================================================================
+
+/// An ECDSA public key over the secp256k1 curve.
+///
+/// The key is defined by the affine coordinates of the corresponding curve point, each encoded as
+/// a 32-byte big-endian integer (i.e., the uncompressed SEC1 encoding of the key without the
+/// leading `0x04` byte).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    x: [u8; 32],
+    y: [u8; 32],
+}
+
+impl PublicKey {
+    /// Returns a new [PublicKey] instantiated from the provided affine coordinates.
+    ///
+    /// The coordinates are not checked to define a point on the secp256k1 curve; a transaction
+    /// executed against an account with an invalid public key cannot be authenticated.
+    pub fn new(x: [u8; 32], y: [u8; 32]) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the x coordinate of this public key.
+    pub fn x(&self) -> &[u8; 32] {
+        &self.x
+    }
+
+    /// Returns the y coordinate of this public key.
+    pub fn y(&self) -> &[u8; 32] {
+        &self.y
+    }
+
+    /// Returns the public key as it is expected by the `auth_tx_ecdsa_secp256k1` procedure.
+    ///
+    /// The key is returned as the projective coordinates (X, Y, Z) of the corresponding curve
+    /// point, with each coordinate being in Montgomery form and consisting of eight 32-bit limbs.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        let mut elements = Vec::with_capacity(24);
+        elements.extend(to_montgomery_elements(&self.x, &BASE_FIELD_MODULUS));
+        elements.extend(to_montgomery_elements(&self.y, &BASE_FIELD_MODULUS));
+
+        let mut one = [0_u8; 32];
+        one[31] = 1;
+        elements.extend(to_montgomery_elements(&one, &BASE_FIELD_MODULUS));
+
+        elements
+    }
+
+    /// Returns the commitment to this public key which is stored in account storage.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.to_elements())
+    }
+
+    /// Returns true if the provided signature is a valid signature of the specified transaction
+    /// message, i.e., of the digest returned by [message_digest()] for this message.
+    ///
+    /// This mirrors the verification performed by the `auth_tx_ecdsa_secp256k1` procedure.
+    #[cfg(feature = "ecdsa")]
+    pub fn verify(&self, message: Word, signature: &Signature) -> bool {
+        let point =
+            k256::EncodedPoint::from_affine_coordinates(&self.x.into(), &self.y.into(), false);
+        let Ok(verifying_key) = VerifyingKey::from_encoded_point(&point) else {
+            return false;
+        };
+        let Ok(signature) = k256::ecdsa::Signature::from_scalars(signature.r, signature.s) else {
+            return false;
+        };
+
+        verifying_key.verify_prehash(&message_digest(message), &signature).is_ok()
+    }
+}
+
+impl From<PublicKey> for Word {
+    fn from(pub_key: PublicKey) -> Self {
+        pub_key.commitment().into()
+    }
+}
+
+// SIGNATURE
+// ================================================================================================
+
+/// An ECDSA signature over the secp256k1 curve.
+///
+/// Both `r` and `s` components of the signature are encoded as 32-byte big-endian integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    r: [u8; 32],
+    s: [u8; 32],
+}
+
+impl Signature {
+    /// Returns a new [Signature] instantiated from the provided `r` and `s` components.
+    pub fn new(r: [u8; 32], s: [u8; 32]) -> Self {
+        Self { r, s }
+    }
+
+    /// Returns the `r` component of this signature.
+    pub fn r(&self) -> &[u8; 32] {
+        &self.r
+    }
+
+    /// Returns the `s` component of this signature.
+    pub fn s(&self) -> &[u8; 32] {
+        &self.s
+    }
+
+    /// Returns the signature as it is expected by the `auth_tx_ecdsa_secp256k1` procedure.
+    ///
+    /// Both components are returned in Montgomery form, each consisting of eight 32-bit limbs.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        let mut elements = Vec::with_capacity(16);
+        elements.extend(to_montgomery_elements(&self.r, &SCALAR_FIELD_MODULUS));
+        elements.extend(to_montgomery_elements(&self.s, &SCALAR_FIELD_MODULUS));
+        elements
+    }
+}
+
+// SECRET KEY
+// ================================================================================================
+
+/// An ECDSA secret key over the secp256k1 curve.
+///
+/// The key is a scalar in the range [1, n), where n is the order of the secp256k1 curve.
+#[cfg(feature = "ecdsa")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretKey {
+    inner: SigningKey,
+}
+
+#[cfg(feature = "ecdsa")]
+impl SecretKey {
+    /// Returns a new [SecretKey] instantiated from the provided 32-byte big-endian scalar, or
+    /// `None` if the scalar is zero or is not smaller than the order of the secp256k1 curve.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        SigningKey::from_bytes(bytes.into()).ok().map(|inner| Self { inner })
+    }
+
+    /// Returns the 32-byte big-endian representation of this secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.inner.to_bytes().into()
+    }
+
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> PublicKey {
+        let point = self.inner.verifying_key().to_encoded_point(false);
+        let x = point.x().expect("the public key is not the point at infinity");
+        let y = point.y().expect("the public key is encoded in uncompressed form");
+        PublicKey::new((*x).into(), (*y).into())
+    }
+
+    /// Signs the specified transaction message, i.e., the digest returned by [message_digest()]
+    /// for this message, and returns the signature.
+    ///
+    /// The nonce of the signature is derived deterministically from the secret key and the message
+    /// as specified by RFC 6979, and thus signing the same message twice yields the same
+    /// signature.
+    pub fn sign(&self, message: Word) -> Signature {
+        let signature: k256::ecdsa::Signature = self
+            .inner
+            .sign_prehash(&message_digest(message))
+            .expect("signing a 32-byte digest does not fail");
+        let (r, s) = signature.split_bytes();
+        Signature::new(r.into(), s.into())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the 32-byte message digest which must be signed to authenticate a transaction with the
+/// specified message.
+///
+/// The digest is the big-endian concatenation of the elements of the message. It must be signed
+/// as is (i.e., without hashing it again).
+pub fn message_digest(message: Word) -> [u8; 32] {
+    let mut digest = [0_u8; 32];
+    for (chunk, element) in digest.chunks_exact_mut(8).zip(message) {
+        chunk.copy_from_slice(&element.as_int().to_be_bytes());
+    }
+    digest
+}
+
+/// Returns the advice map entry required to authenticate a transaction with the specified
+/// message using the provided public key and signature.
+///
+/// The entry maps hash(PUB_KEY, M) to the public key data followed by the signature data, where
+/// PUB_KEY is the commitment to the public key and M is the transaction message.
+pub fn signature_advice_entry(
+    pub_key: &PublicKey,
+    message: Word,
+    signature: &Signature,
+) -> (Digest, Vec<Felt>) {
+    let key = Hasher::merge(&[pub_key.commitment(), message.into()]);
+
+    let mut values = pub_key.to_elements();
+    values.extend(signature.to_elements());
+
+    (key, values)
+}
+
+/// Converts the provided big-endian integer into Montgomery form with respect to the specified
+/// modulus and returns it as eight 32-bit limbs arranged as expected by the VM.
+///
+/// The least significant limbs come first, with every group of four limbs reversed so that loading
+/// a word from memory puts the least significant limb of the group on top of the stack.
+fn to_montgomery_elements(value: &[u8; 32], modulus: &U256) -> [Felt; 8] {
+    let mut value = reduce(&u256_from_be_bytes(value), modulus);
+
+    // multiply by 2^256 via repeated modular doubling
+    for _ in 0..256 {
+        value = add_mod(&value, &value, modulus);
+    }
+
+    let limbs: Vec<u32> =
+        value.iter().flat_map(|&limb| [limb as u32, (limb >> 32) as u32]).collect();
+
+    let mut elements = [Felt::new(0); 8];
+    for (i, limb) in limbs.into_iter().enumerate() {
+        elements[(i / 4) * 4 + 3 - (i % 4)] = Felt::from(limb);
+    }
+    elements
+}
+
+/// Returns a 256-bit integer from its big-endian byte representation.
+fn u256_from_be_bytes(bytes: &[u8; 32]) -> U256 {
+    let mut result = [0_u64; 4];
+    for (i, chunk) in bytes.chunks_exact(8).enumerate() {
+        result[3 - i] = u64::from_be_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+    }
+    result
+}
+
+/// Returns true if `a` is smaller than `b`.
+fn lt(a: &U256, b: &U256) -> bool {
+    a.iter().rev().cmp(b.iter().rev()).is_lt()
+}
+
+/// Returns `a - b` modulo 2^256.
+fn sub(a: &U256, b: &U256) -> U256 {
+    let mut result = [0_u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, borrow_1) = a[i].overflowing_sub(b[i]);
+        let (diff, borrow_2) = diff.overflowing_sub(borrow as u64);
+        result[i] = diff;
+        borrow = borrow_1 || borrow_2;
+    }
+    result
+}
+
+/// Returns `a + b` modulo 2^256 together with the carry bit.
+fn add(a: &U256, b: &U256) -> (U256, bool) {
+    let mut result = [0_u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, carry_1) = a[i].overflowing_add(b[i]);
+        let (sum, carry_2) = sum.overflowing_add(carry as u64);
+        result[i] = sum;
+        carry = carry_1 || carry_2;
+    }
+    (result, carry)
+}
+
+/// Returns `a mod m` for `a < 2m`.
+fn reduce(a: &U256, m: &U256) -> U256 {
+    if lt(a, m) {
+        *a
+    } else {
+        sub(a, m)
+    }
+}
+
+/// Returns `a + b mod m` for `a, b < m`.
+fn add_mod(a: &U256, b: &U256, m: &U256) -> U256 {
+    let (sum, carry) = add(a, b);
+    if carry || !lt(&sum, m) {
+        sub(&sum, m)
+    } else {
+        sum
+    }
+}
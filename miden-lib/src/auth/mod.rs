@@ -0,0 +1,66 @@
+use miden_objects::{
+    accounts::AccountId, crypto::dsa::rpo_falcon512, Digest, Felt, Hasher, Word, EMPTY_WORD, ZERO,
+};
+
+pub mod ecdsa_secp256k1;
+
+/// Defines authentication schemes available to standard and faucet accounts.
+pub enum AuthScheme {
+    /// A single-key authentication scheme which relies RPO Falcon512 signatures. RPO Falcon512 is a
+    /// variant of the [Falcon](https://falcon-sign.info/) signature scheme. This variant differs from
+    /// the standard in that instead of using SHAKE256 hash function in the hash-to-point algorithm we
+    /// use RPO256. This makes the signature more efficient to verify in Miden VM.
+    RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
+    /// A single-key authentication scheme which relies on ECDSA signatures over the secp256k1
+    /// curve. This makes it possible to authenticate transactions with keys managed by existing
+    /// wallets and hardware devices, at the cost of a considerably more expensive verification.
+    EcdsaSecp256k1 { pub_key: ecdsa_secp256k1::PublicKey },
+    /// An authentication scheme which does not verify any signature. Anyone can execute
+    /// transactions against an account using this scheme, and thus it should be used only for
+    /// public and test accounts.
+    NoAuth,
+}
+
+impl AuthScheme {
+    /// Returns the name of the module in `miden::contracts::auth` which defines the
+    /// authentication procedure of this scheme, together with the name of the procedure.
+    pub(crate) fn auth_procedure(&self) -> (&'static str, &'static str) {
+        match self {
+            AuthScheme::RpoFalcon512 { .. } => ("basic", "auth_tx_rpo_falcon512"),
+            AuthScheme::EcdsaSecp256k1 { .. } => ("ecdsa_secp256k1", "auth_tx_ecdsa_secp256k1"),
+            AuthScheme::NoAuth => ("basic", "auth_tx_no_auth"),
+        }
+    }
+
+    /// Returns the data which the authentication procedure of this scheme expects to find in the
+    /// account storage slot 0.
+    pub(crate) fn storage_slot_0_data(&self) -> Word {
+        match self {
+            AuthScheme::RpoFalcon512 { pub_key } => (*pub_key).into(),
+            AuthScheme::EcdsaSecp256k1 { pub_key } => (*pub_key).into(),
+            AuthScheme::NoAuth => EMPTY_WORD,
+        }
+    }
+}
+
+/// Returns the message which must be signed to authenticate a transaction executed against the
+/// specified account.
+///
+/// The message is computed as:
+///
+/// M = hash(OUTPUT_NOTES_HASH, hash(INPUT_NOTES_HASH, hash([nonce, 0, 0, 0], [account_id, 0, 0, 0])))
+///
+/// where `nonce` is the nonce of the account at the time the authentication procedure is invoked.
+pub fn tx_auth_message(
+    account_id: AccountId,
+    nonce: Felt,
+    input_notes_hash: Digest,
+    output_notes_hash: Digest,
+) -> Word {
+    let nonce_word: Digest = [nonce, ZERO, ZERO, ZERO].into();
+    let account_id_word: Digest = [account_id.into(), ZERO, ZERO, ZERO].into();
+
+    let message = Hasher::merge(&[nonce_word, account_id_word]);
+    let message = Hasher::merge(&[input_notes_hash, message]);
+    Hasher::merge(&[output_notes_hash, message]).into()
+}
@@ -11,7 +11,7 @@ use miden_objects::{
     utils::serde::Deserializable,
 };
 
-mod auth;
+pub mod auth;
 pub use auth::AuthScheme;
 
 pub mod accounts;
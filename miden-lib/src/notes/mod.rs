@@ -2,19 +2,25 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::AccountId,
-    assets::Asset,
+    assets::{Asset, FungibleAsset},
     crypto::rand::FeltRng,
     notes::{
-        Note, NoteAssets, NoteExecutionMode, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
-        NoteType,
+        Note, NoteAssets, NoteDetails, NoteExecutionMode, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteTag, NoteType,
     },
-    NoteError, Word, ZERO,
+    Digest, NoteError, Word, ZERO,
 };
 
 use self::utils::build_note_script;
 
 pub mod utils;
 
+mod well_known_note;
+pub use well_known_note::{
+    HtlcNoteInputs, P2idNoteInputs, P2idrNoteInputs, P2idtNoteInputs, PartialSwapNoteInputs,
+    SwapNoteInputs, WellKnownNote, WellKnownNoteInputs,
+};
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -35,8 +41,7 @@ pub fn create_p2id_note<R: FeltRng>(
     note_type: NoteType,
     mut rng: R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = build_note_script(WellKnownNote::P2ID.script_bytes())?;
 
     let inputs = NoteInputs::new(vec![target.into()])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
@@ -69,8 +74,7 @@ pub fn create_p2idr_note<R: FeltRng>(
     recall_height: u32,
     mut rng: R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = build_note_script(WellKnownNote::P2IDR.script_bytes())?;
 
     let inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
@@ -83,39 +87,131 @@ pub fn create_p2idr_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates an HTLC note - hash time-locked contract with recall after a certain block height.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// under the condition that the target reveals the preimage of `secret_hash`. The secret is passed
+/// to the note script via note args, and the secret hash is computed as
+/// `Hasher::hash_elements(&secret)`. Additionally, the sender can reclaim the assets if the note
+/// has not been consumed by the target before the `recall_height` block.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `HTLC` script fails.
+pub fn create_htlc_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    secret_hash: Digest,
+    recall_height: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let note_script = build_note_script(WellKnownNote::HTLC.script_bytes())?;
+
+    let mut inputs = secret_hash.as_elements().to_vec();
+    inputs.extend_from_slice(&[target.into(), recall_height.into()]);
+    let inputs = NoteInputs::new(inputs)?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a P2IDT note - pay to id with a time lock and recall after a certain timestamp.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// by specifying the target's account ID. The target can consume the note only in transactions
+/// whose reference block has a timestamp of at least `unlock_timestamp`. Additionally, the
+/// sender can reclaim the assets in transactions whose reference block has a timestamp of at least
+/// `reclaim_timestamp`.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDT` script fails.
+pub fn create_p2idt_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    unlock_timestamp: u32,
+    reclaim_timestamp: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let note_script = build_note_script(WellKnownNote::P2IDT.script_bytes())?;
+
+    let inputs =
+        NoteInputs::new(vec![target.into(), unlock_timestamp.into(), reclaim_timestamp.into()])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a SWAP note - swap of assets between two accounts.
 ///
 /// This script enables a swap of 2 assets between the `sender` account and any other account that
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
-/// new P2ID note with `sender` as target, containing the `requested_asset`.
+/// new P2ID note of type `payback_note_type` with `sender` as target, containing the
+/// `requested_asset`.
+///
+/// Besides the SWAP note, the details of the expected payback note are returned. The consumer of
+/// the SWAP note can provide them to the transaction via
+/// [TransactionArgs::add_expected_output_note()](miden_objects::transaction::TransactionArgs::add_expected_output_note),
+/// which is required for the payback note to be public.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `SWAP` script fails.
+/// Returns an error if:
+/// - Deserialization or compilation of the `SWAP` script fails.
+/// - The payback note type is [NoteType::Encrypted], which is not supported by the SWAP script.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
     requested_asset: Asset,
     note_type: NoteType,
+    payback_note_type: NoteType,
     mut rng: R,
-) -> Result<(Note, Word), NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
-    let note_script = build_note_script(bytes)?;
+) -> Result<(Note, NoteDetails), NoteError> {
+    if payback_note_type == NoteType::Encrypted {
+        return Err(NoteError::InvalidNoteType(payback_note_type));
+    }
+
+    let note_script = build_note_script(WellKnownNote::SWAP.script_bytes())?;
 
     let payback_serial_num = rng.draw_word();
-    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+    let payback_recipient = NoteRecipient::new(
+        payback_serial_num,
+        WellKnownNote::P2ID.script(),
+        NoteInputs::new(vec![sender.into()])?,
+    );
+    let payback_recipient_word: Word = payback_recipient.digest().into();
     let asset_word: Word = requested_asset.into();
-    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    let payback_tag =
+        NoteTag::from_account_id(sender, NoteExecutionMode::Local)?.validate(payback_note_type)?;
 
     let inputs = NoteInputs::new(vec![
-        payback_recipient[0],
-        payback_recipient[1],
-        payback_recipient[2],
-        payback_recipient[3],
+        payback_recipient_word[0],
+        payback_recipient_word[1],
+        payback_recipient_word[2],
+        payback_recipient_word[3],
         asset_word[0],
         asset_word[1],
         asset_word[2],
         asset_word[3],
         payback_tag.inner().into(),
+        payback_note_type.into(),
     ])?;
 
     // TODO: build the tag for the SWAP use case
@@ -128,5 +224,161 @@ pub fn create_swap_note<R: FeltRng>(
     let recipient = NoteRecipient::new(serial_num, note_script, inputs);
     let note = Note::new(vault, metadata, recipient);
 
-    Ok((note, payback_serial_num))
+    let payback_assets = NoteAssets::new(vec![requested_asset])?;
+    let payback_note = NoteDetails::new(payback_assets, payback_recipient);
+
+    Ok((note, payback_note))
+}
+
+/// Generates a PSWAP note - partially fillable swap of assets between two accounts.
+///
+/// This script enables the `sender` account to offer the fungible `offered_asset` for the fungible
+/// `requested_asset` at a fixed price. The consumer of the note specifies the amount of the
+/// requested asset it provides via note args (as `[fill_amount, 0, 0, 0]`), receives a
+/// proportional share of the offered asset, and creates a new P2ID note with `sender` as target,
+/// containing the provided part of the requested asset. If the requested asset is not provided in
+/// full, the consumer also creates a new PSWAP note which offers the rest of the offered asset for
+/// the rest of the requested asset.
+///
+/// The PSWAP note and all remainder notes created when it is partially filled carry the provided
+/// `tag`, and thus the remainder of the offer can be discovered in the same way as the original
+/// note until the offer is filled in full.
+///
+/// The notes created when consuming the PSWAP note can be computed via
+/// [create_partial_swap_fill_notes()].
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the assets is not fungible.
+/// - Any of the asset amounts is zero, or the product of the asset amounts does not fit into 64
+///   bits.
+/// - The tag is not consistent with the note type.
+pub fn create_partial_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    note_type: NoteType,
+    tag: NoteTag,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let offered = unwrap_fungible(offered_asset)?;
+    let requested = unwrap_fungible(requested_asset)?;
+    if offered.amount() == 0
+        || requested.amount() == 0
+        || offered.amount().checked_mul(requested.amount()).is_none()
+    {
+        return Err(NoteError::InvalidPartialSwapAmounts {
+            offered: offered.amount(),
+            requested: requested.amount(),
+        });
+    }
+
+    let note_script = WellKnownNote::PSWAP.script();
+
+    let inputs = PartialSwapNoteInputs {
+        requested_asset,
+        payback_script_root: WellKnownNote::P2ID.script_root(),
+        serial_seed: rng.draw_word(),
+        payback_tag: NoteTag::from_account_id(sender, NoteExecutionMode::Local)?,
+        remainder_tag: tag,
+        note_type,
+        creator: sender,
+    };
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let vault = NoteAssets::new(vec![offered_asset])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs.to_note_inputs()?);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Returns the notes created by the `consumer` account when consuming the provided PSWAP note with
+/// the specified fill amount.
+///
+/// The first returned note is the P2ID note paying the fill amount of the requested asset to the
+/// creator of the PSWAP note. The second returned note is the PSWAP note offering the remainder of
+/// the offered asset, and is returned only if the fill amount is smaller than the requested
+/// amount.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided note is not a PSWAP note, or its inputs are malformed.
+/// - The fill amount is zero or greater than the requested amount.
+pub fn create_partial_swap_fill_notes(
+    note: &Note,
+    consumer: AccountId,
+    fill_amount: u64,
+) -> Result<(Note, Option<Note>), NoteError> {
+    let inputs = match WellKnownNote::parse_note(note)? {
+        Some((_, WellKnownNoteInputs::PSWAP(inputs))) => inputs,
+        _ => return Err(NoteError::NotPartialSwapNote(note.id())),
+    };
+    let offered = match note.assets().iter().next() {
+        Some(asset) if note.assets().num_assets() == 1 => unwrap_fungible(*asset)?,
+        _ => return Err(NoteError::NotPartialSwapNote(note.id())),
+    };
+    let requested = unwrap_fungible(inputs.requested_asset)?;
+    if fill_amount == 0 || fill_amount > requested.amount() {
+        return Err(NoteError::InvalidPartialSwapFillAmount {
+            fill: fill_amount,
+            requested: requested.amount(),
+        });
+    }
+
+    // the payback note is a P2ID note targeting the creator of the PSWAP note
+    let payback_asset = FungibleAsset::new(requested.faucet_id(), fill_amount)
+        .map_err(NoteError::InvalidAssetData)?;
+    let payback_recipient = NoteRecipient::new(
+        inputs.serial_seed,
+        WellKnownNote::P2ID.script(),
+        NoteInputs::new(vec![inputs.creator.into()])?,
+    );
+    let payback_note = Note::new(
+        NoteAssets::new(vec![payback_asset.into()])?,
+        NoteMetadata::new(consumer, inputs.note_type, inputs.payback_tag, ZERO)?,
+        payback_recipient,
+    );
+
+    if fill_amount == requested.amount() {
+        return Ok((payback_note, None));
+    }
+
+    // the amount of the offered asset received by the consumer is rounded down
+    let offered_out = offered.amount() as u128 * fill_amount as u128 / requested.amount() as u128;
+    let remainder_asset =
+        FungibleAsset::new(offered.faucet_id(), offered.amount() - offered_out as u64)
+            .map_err(NoteError::InvalidAssetData)?;
+    let remainder_inputs = PartialSwapNoteInputs {
+        requested_asset: FungibleAsset::new(
+            requested.faucet_id(),
+            requested.amount() - fill_amount,
+        )
+        .map_err(NoteError::InvalidAssetData)?
+        .into(),
+        serial_seed: inputs.remainder_serial_seed(),
+        ..inputs
+    };
+    let remainder_recipient = NoteRecipient::new(
+        remainder_inputs.serial_seed,
+        note.script().clone(),
+        remainder_inputs.to_note_inputs()?,
+    );
+    let remainder_note = Note::new(
+        NoteAssets::new(vec![remainder_asset.into()])?,
+        NoteMetadata::new(consumer, inputs.note_type, inputs.remainder_tag, ZERO)?,
+        remainder_recipient,
+    );
+
+    Ok((payback_note, Some(remainder_note)))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn unwrap_fungible(asset: Asset) -> Result<FungibleAsset, NoteError> {
+    match asset {
+        Asset::Fungible(asset) => Ok(asset),
+        Asset::NonFungible(_) => Err(NoteError::PartialSwapAssetNotFungible(asset)),
+    }
 }
@@ -1,8 +1,11 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ProgramAst, notes::NoteScript, Digest, Hasher, NoteError, Word,
-    ZERO,
+    accounts::AccountId,
+    assembly::ProgramAst,
+    notes::{NoteInputs, NoteRecipient, NoteScript},
+    Digest, NoteError, Word,
 };
 
+use super::WellKnownNote;
 use crate::transaction::TransactionKernel;
 
 /// Creates the note_script from inputs
@@ -17,19 +20,9 @@ pub fn build_note_script(bytes: &[u8]) -> Result<NoteScript, NoteError> {
 
 /// Creates the RECIPIENT for the P2ID note script created by the SWAP script
 pub fn build_p2id_recipient(target: AccountId, serial_num: Word) -> Result<Digest, NoteError> {
-    // TODO: add lazy_static initialization or compile-time optimization instead of re-generating
-    // the script hash every time we call the SWAP script
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let note_script = build_note_script(bytes)?;
+    let note_script = build_note_script(WellKnownNote::P2ID.script_bytes())?;
 
-    let script_hash = note_script.hash();
+    let note_inputs = NoteInputs::new(vec![target.into()])?;
 
-    let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-
-    let merge_script = Hasher::merge(&[serial_num_hash, script_hash]);
-
-    Ok(Hasher::merge(&[
-        merge_script,
-        Hasher::hash_elements(&[target.into(), ZERO, ZERO, ZERO]),
-    ]))
+    Ok(NoteRecipient::new(serial_num, note_script, note_inputs).digest())
 }
@@ -0,0 +1,333 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    notes::{Note, NoteInputs, NoteScript, NoteTag, NoteType},
+    Digest, Felt, Hasher, NoteError, Word,
+};
+
+use super::utils::build_note_script;
+
+// NOTE SCRIPT ROOTS
+// ================================================================================================
+
+include!(concat!(env!("OUT_DIR"), "/note_script_roots.rs"));
+
+// WELL KNOWN NOTE
+// ================================================================================================
+
+/// Standard note scripts provided by the Miden library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownNote {
+    P2ID,
+    P2IDR,
+    P2IDT,
+    HTLC,
+    SWAP,
+    PSWAP,
+}
+
+impl WellKnownNote {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// All well-known notes.
+    pub const ALL: [Self; 6] =
+        [Self::P2ID, Self::P2IDR, Self::P2IDT, Self::HTLC, Self::SWAP, Self::PSWAP];
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the well-known note which uses the script of the provided note, or None if the
+    /// note script is not one of the standard note scripts.
+    pub fn from_note(note: &Note) -> Option<Self> {
+        Self::from_script_root(note.script().hash())
+    }
+
+    /// Returns the well-known note with the script of the specified MAST root, or None if the
+    /// root does not match any of the standard note scripts.
+    pub fn from_script_root(script_root: Digest) -> Option<Self> {
+        Self::ALL.into_iter().find(|note| note.script_root() == script_root)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the script of this well-known note.
+    pub fn script(&self) -> NoteScript {
+        build_note_script(self.script_bytes()).expect("standard note scripts are valid")
+    }
+
+    /// Returns the serialized AST of the script of this well-known note.
+    pub(crate) fn script_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::P2ID => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"))
+            },
+            Self::P2IDR => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"))
+            },
+            Self::P2IDT => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDT.masb"))
+            },
+            Self::HTLC => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/HTLC.masb"))
+            },
+            Self::SWAP => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"))
+            },
+            Self::PSWAP => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PSWAP.masb"))
+            },
+        }
+    }
+
+    /// Returns the MAST root of the script of this well-known note.
+    ///
+    /// The roots of the standard note scripts are computed when the Miden library is built, and
+    /// thus the script is not compiled by this method.
+    pub fn script_root(&self) -> Digest {
+        let root = match self {
+            Self::P2ID => P2ID_SCRIPT_ROOT,
+            Self::P2IDR => P2IDR_SCRIPT_ROOT,
+            Self::P2IDT => P2IDT_SCRIPT_ROOT,
+            Self::HTLC => HTLC_SCRIPT_ROOT,
+            Self::SWAP => SWAP_SCRIPT_ROOT,
+            Self::PSWAP => PSWAP_SCRIPT_ROOT,
+        };
+        Digest::new(root.map(Felt::new))
+    }
+
+    /// Returns the number of inputs expected by the script of this well-known note.
+    pub fn num_inputs(&self) -> usize {
+        match self {
+            Self::P2ID => 1,
+            Self::P2IDR => 2,
+            Self::P2IDT => 3,
+            Self::HTLC => 6,
+            Self::SWAP => 10,
+            Self::PSWAP => 16,
+        }
+    }
+
+    // INPUT PARSING
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses the provided inputs into the typed inputs of this well-known note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of inputs differs from the number of inputs expected by the note script.
+    /// - Any of the inputs is not a valid value for the input it represents (e.g., an invalid
+    ///   account ID or asset).
+    pub fn parse_inputs(&self, inputs: &NoteInputs) -> Result<WellKnownNoteInputs, NoteError> {
+        let values = inputs.values();
+        if values.len() != self.num_inputs() {
+            return Err(NoteError::InvalidNoteInputsLen {
+                expected: self.num_inputs(),
+                actual: values.len(),
+            });
+        }
+
+        match self {
+            Self::P2ID => Ok(WellKnownNoteInputs::P2ID(P2idNoteInputs {
+                target: parse_account_id(values[0])?,
+            })),
+            Self::P2IDR => Ok(WellKnownNoteInputs::P2IDR(P2idrNoteInputs {
+                target: parse_account_id(values[0])?,
+                recall_height: values[1]
+                    .as_int()
+                    .try_into()
+                    .map_err(|_| NoteError::InvalidRecallHeight(values[1].as_int()))?,
+            })),
+            Self::P2IDT => Ok(WellKnownNoteInputs::P2IDT(P2idtNoteInputs {
+                target: parse_account_id(values[0])?,
+                unlock_timestamp: parse_timestamp(values[1])?,
+                reclaim_timestamp: parse_timestamp(values[2])?,
+            })),
+            Self::HTLC => {
+                let secret_hash: Word = [values[0], values[1], values[2], values[3]];
+                Ok(WellKnownNoteInputs::HTLC(HtlcNoteInputs {
+                    secret_hash: secret_hash.into(),
+                    target: parse_account_id(values[4])?,
+                    recall_height: values[5]
+                        .as_int()
+                        .try_into()
+                        .map_err(|_| NoteError::InvalidRecallHeight(values[5].as_int()))?,
+                }))
+            },
+            Self::SWAP => {
+                let payback_recipient: Word = [values[0], values[1], values[2], values[3]];
+                let requested_asset: Word = [values[4], values[5], values[6], values[7]];
+                Ok(WellKnownNoteInputs::SWAP(SwapNoteInputs {
+                    payback_recipient: payback_recipient.into(),
+                    requested_asset: Asset::try_from(requested_asset)
+                        .map_err(NoteError::InvalidAssetData)?,
+                    payback_tag: parse_note_tag(values[8])?,
+                    payback_note_type: NoteType::try_from(values[9])?,
+                }))
+            },
+            Self::PSWAP => {
+                let requested_asset: Word = [values[0], values[1], values[2], values[3]];
+                let payback_script_root: Word = [values[4], values[5], values[6], values[7]];
+                Ok(WellKnownNoteInputs::PSWAP(PartialSwapNoteInputs {
+                    requested_asset: Asset::try_from(requested_asset)
+                        .map_err(NoteError::InvalidAssetData)?,
+                    payback_script_root: payback_script_root.into(),
+                    serial_seed: [values[8], values[9], values[10], values[11]],
+                    payback_tag: parse_note_tag(values[12])?,
+                    remainder_tag: parse_note_tag(values[13])?,
+                    note_type: NoteType::try_from(values[14])?,
+                    creator: parse_account_id(values[15])?,
+                }))
+            },
+        }
+    }
+
+    /// Returns the well-known note used by the provided note together with the note's parsed
+    /// inputs, or None if the note script is not one of the standard note scripts.
+    ///
+    /// # Errors
+    /// Returns an error if the note uses a standard note script, but its inputs are malformed.
+    pub fn parse_note(note: &Note) -> Result<Option<(Self, WellKnownNoteInputs)>, NoteError> {
+        match Self::from_note(note) {
+            Some(well_known_note) => {
+                let inputs = well_known_note.parse_inputs(note.inputs())?;
+                Ok(Some((well_known_note, inputs)))
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+// WELL KNOWN NOTE INPUTS
+// ================================================================================================
+
+/// Typed inputs of a well-known note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownNoteInputs {
+    P2ID(P2idNoteInputs),
+    P2IDR(P2idrNoteInputs),
+    P2IDT(P2idtNoteInputs),
+    HTLC(HtlcNoteInputs),
+    SWAP(SwapNoteInputs),
+    PSWAP(PartialSwapNoteInputs),
+}
+
+/// Inputs of a P2ID note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2idNoteInputs {
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+}
+
+/// Inputs of a P2IDR note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2idrNoteInputs {
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+    /// Block height starting from which the sender can reclaim the note.
+    pub recall_height: u32,
+}
+
+/// Inputs of a P2IDT note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2idtNoteInputs {
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+    /// Block timestamp starting from which the target can consume the note.
+    pub unlock_timestamp: u32,
+    /// Block timestamp starting from which the sender can reclaim the note.
+    pub reclaim_timestamp: u32,
+}
+
+/// Inputs of an HTLC note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtlcNoteInputs {
+    /// Hash of the secret which the target has to provide to consume the note.
+    pub secret_hash: Digest,
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+    /// Block height starting from which the sender can reclaim the note.
+    pub recall_height: u32,
+}
+
+/// Inputs of a SWAP note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapNoteInputs {
+    /// Recipient of the P2ID note created for the sender when the SWAP note is consumed.
+    pub payback_recipient: Digest,
+    /// Asset the sender requests in exchange for the offered asset.
+    pub requested_asset: Asset,
+    /// Tag of the P2ID note created for the sender when the SWAP note is consumed.
+    pub payback_tag: NoteTag,
+    /// Type of the P2ID note created for the sender when the SWAP note is consumed.
+    pub payback_note_type: NoteType,
+}
+
+/// Inputs of a partially fillable SWAP (PSWAP) note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSwapNoteInputs {
+    /// Asset the sender requests in exchange for the offered asset.
+    pub requested_asset: Asset,
+    /// Root of the script of the note created for the sender when the PSWAP note is consumed.
+    pub payback_script_root: Digest,
+    /// Serial number of the note created for the sender when the PSWAP note is consumed. The
+    /// serial numbers of the remainder notes are derived from this value.
+    pub serial_seed: Word,
+    /// Tag of the note created for the sender when the PSWAP note is consumed.
+    pub payback_tag: NoteTag,
+    /// Tag of the PSWAP note created for the remainder of a partially filled PSWAP note.
+    pub remainder_tag: NoteTag,
+    /// Type of the payback and remainder notes.
+    pub note_type: NoteType,
+    /// ID of the account which created the original PSWAP note, and which is the target of the
+    /// payback notes.
+    pub creator: AccountId,
+}
+
+impl PartialSwapNoteInputs {
+    /// Returns the serial seed of the PSWAP note created for the remainder of this note. The
+    /// seed is also used as the serial number of the remainder note.
+    pub fn remainder_serial_seed(&self) -> Word {
+        Hasher::merge(&[self.serial_seed.into(), self.serial_seed.into()]).into()
+    }
+
+    /// Returns these inputs as [NoteInputs] of a PSWAP note.
+    pub fn to_note_inputs(&self) -> Result<NoteInputs, NoteError> {
+        let requested_asset: Word = self.requested_asset.into();
+        let payback_script_root: Word = self.payback_script_root.into();
+
+        let mut values = Vec::with_capacity(WellKnownNote::PSWAP.num_inputs());
+        values.extend_from_slice(&requested_asset);
+        values.extend_from_slice(&payback_script_root);
+        values.extend_from_slice(&self.serial_seed);
+        values.extend_from_slice(&[
+            self.payback_tag.into(),
+            self.remainder_tag.into(),
+            self.note_type.into(),
+            self.creator.into(),
+        ]);
+
+        NoteInputs::new(values)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn parse_account_id(value: Felt) -> Result<AccountId, NoteError> {
+    AccountId::try_from(value).map_err(NoteError::InvalidAccountIdInput)
+}
+
+fn parse_timestamp(value: Felt) -> Result<u32, NoteError> {
+    value
+        .as_int()
+        .try_into()
+        .map_err(|_| NoteError::InvalidNoteTimestamp(value.as_int()))
+}
+
+fn parse_note_tag(value: Felt) -> Result<NoteTag, NoteError> {
+    NoteTag::try_from(value).map_err(|_| NoteError::InvalidNoteTagValue(value.as_int()))
+}
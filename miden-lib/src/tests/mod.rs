@@ -8,11 +8,14 @@ use super::Library;
 mod test_account;
 mod test_asset;
 mod test_asset_vault;
+#[cfg(feature = "ecdsa")]
+mod test_auth;
 mod test_epilogue;
 mod test_faucet;
 mod test_note;
 mod test_prologue;
 mod test_tx;
+mod test_well_known_note;
 
 // CONSTANTS
 // ================================================================================================
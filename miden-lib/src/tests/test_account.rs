@@ -6,13 +6,14 @@ use miden_objects::{
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
     crypto::merkle::LeafIndex,
+    Digest,
 };
 use mock::{
-    constants::{
-        CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0,
-    },
     mock::{
-        account::{storage_item_0, storage_item_1, MockAccountType},
+        account::{
+            storage_array_3, storage_item_0, storage_item_1, storage_item_2, storage_item_3,
+            storage_map_2, MockAccountType, STORAGE_LEAVES_2,
+        },
         host::MockHost,
         notes::AssetPreservationStatus,
         transaction::{mock_executed_tx, mock_inputs},
@@ -326,13 +327,62 @@ fn test_set_item() {
     let _process = run_tx(&transaction).unwrap();
 }
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_get_map_item() {
     let (tx_inputs, tx_args) =
         mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
 
+    let storage_item = storage_item_2();
+    for (key, value) in STORAGE_LEAVES_2 {
+        let code = format!(
+            "
+            use.miden::account
+            use.miden::kernels::tx::prologue
+
+            begin
+                # prepare the transaction
+                exec.prologue::prepare_transaction
+
+                # push the item's KEY onto the stack
+                push.{map_key}
+
+                # push the account storage item index
+                push.{item_index}
+
+                # get the map item
+                exec.account::get_map_item
+
+                # assert the item value is correct
+                push.{map_value} assert_eqw
+            end
+            ",
+            item_index = storage_item.index,
+            map_key = prepare_word(&key),
+            map_value = prepare_word(&value),
+        );
+
+        let transaction =
+            prepare_transaction(tx_inputs.clone(), tx_args.clone(), code.as_str(), None);
+        let _process = run_tx(&transaction).unwrap();
+    }
+}
+
+#[test]
+fn test_set_map_item() {
+    let (new_key, new_value) = (
+        Digest::new([Felt::new(109), Felt::new(110), Felt::new(111), Felt::new(112)]),
+        [Felt::new(9_u64), Felt::new(10_u64), Felt::new(11_u64), Felt::new(12_u64)],
+    );
+
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let storage_item = storage_item_2();
+
+    // compute the expected root of the map after the update
+    let mut storage_map = storage_map_2();
+    storage_map.insert(new_key, new_value);
+
     let code = format!(
         "
         use.miden::account
@@ -342,29 +392,186 @@ fn test_get_map_item() {
             # prepare the transaction
             exec.prologue::prepare_transaction
 
-            # push the account storage index the child root is stored at
-            push.{CHILD_ROOT_PARENT_LEAF_INDEX}
+            # push the new VALUE and KEY onto the stack
+            push.{new_value}
+            push.{new_key}
 
-            # get the child root
-            exec.account::get_item
+            # push the account storage item index
+            push.{item_index}
 
-            # get a value from the child tree
-            push.{CHILD_STORAGE_INDEX_0}
+            # set the map item
+            exec.account::set_map_item
 
-            # get the item
-            push.{CHILD_SMT_DEPTH} mtree_get
+            # assert the old map root and the old value are correct
+            push.{old_root} assert_eqw
+            padw assert_eqw
+
+            # assert the new map root is stored in the storage slot
+            push.{item_index} exec.account::get_item
+            push.{new_root} assert_eqw
 
-            # assert the child value is correct
-            push.{child_value} assert_eqw
+            # assert the new value can be read from the map
+            push.{new_key} push.{item_index} exec.account::get_map_item
+            push.{new_value} assert_eqw
         end
         ",
-        child_value = prepare_word(&CHILD_STORAGE_VALUE_0)
+        item_index = storage_item.index,
+        new_key = prepare_word(&new_key),
+        new_value = prepare_word(&new_value),
+        old_root = prepare_word(&storage_item.slot.value),
+        new_root = prepare_word(&storage_map.root()),
     );
 
     let transaction = prepare_transaction(tx_inputs, tx_args, code.as_str(), None);
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_get_map_item_from_non_map_slot() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # try to get a map item from a value slot
+            padw push.{item_index}
+            exec.account::get_map_item
+        end
+        ",
+        item_index = storage_item_0().index,
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code.as_str(), None);
+    let process = run_tx(&transaction);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_get_array_item() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let storage_item = storage_item_3();
+    let storage_array = storage_array_3();
+    for position in [0, 3, 9, 15] {
+        let code = format!(
+            "
+            use.miden::account
+            use.miden::kernels::tx::prologue
+
+            begin
+                # prepare the transaction
+                exec.prologue::prepare_transaction
+
+                # push the position of the element and the account storage item index
+                push.{position} push.{item_index}
+
+                # get the array element
+                exec.account::get_array_item
+
+                # assert the element value is correct
+                push.{element_value} assert_eqw
+            end
+            ",
+            item_index = storage_item.index,
+            element_value = prepare_word(&storage_array.get(position).unwrap()),
+        );
+
+        let transaction =
+            prepare_transaction(tx_inputs.clone(), tx_args.clone(), code.as_str(), None);
+        let _process = run_tx(&transaction).unwrap();
+    }
+}
+
+#[test]
+fn test_set_array_item() {
+    let (position, new_value) =
+        (9, [Felt::new(19_u64), Felt::new(20_u64), Felt::new(21_u64), Felt::new(22_u64)]);
+
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let storage_item = storage_item_3();
+
+    // compute the expected root of the array after the update
+    let mut storage_array = storage_array_3();
+    let old_value = storage_array.insert(position, new_value).unwrap();
+
+    let code = format!(
+        "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # push the new VALUE, the position of the element and the account storage item index
+            push.{new_value} push.{position} push.{item_index}
+
+            # set the array element
+            exec.account::set_array_item
+
+            # assert the old array root and the old value are correct
+            push.{old_root} assert_eqw
+            push.{old_value} assert_eqw
+
+            # assert the new array root is stored in the storage slot
+            push.{item_index} exec.account::get_item
+            push.{new_root} assert_eqw
+
+            # assert the new value can be read from the array
+            push.{position} push.{item_index} exec.account::get_array_item
+            push.{new_value} assert_eqw
+        end
+        ",
+        item_index = storage_item.index,
+        new_value = prepare_word(&new_value),
+        old_value = prepare_word(&old_value),
+        old_root = prepare_word(&storage_item.slot.value),
+        new_root = prepare_word(&storage_array.root()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code.as_str(), None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
+#[test]
+fn test_get_array_item_from_non_array_slot() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    for item_index in [storage_item_0().index, storage_item_2().index] {
+        let code = format!(
+            "
+            use.miden::account
+            use.miden::kernels::tx::prologue
+
+            begin
+                # prepare the transaction
+                exec.prologue::prepare_transaction
+
+                # try to get an array element from a value or a map slot
+                push.0 push.{item_index}
+                exec.account::get_array_item
+            end
+            "
+        );
+
+        let transaction =
+            prepare_transaction(tx_inputs.clone(), tx_args.clone(), code.as_str(), None);
+        let process = run_tx(&transaction);
+        assert!(process.is_err());
+    }
+}
+
 // ACCOUNT VAULT TESTS
 // ================================================================================================
 
@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use miden_objects::{utils::hex_to_bytes, Digest, Word, ONE, ZERO};
+use mock::{procedures::prepare_word, run_within_host};
+use vm_processor::{AdviceInputs, DefaultHost, ExecutionError};
+
+use super::{ContextId, Felt, MemAdviceProvider, ProcessState, StackInputs};
+use crate::auth::ecdsa_secp256k1::{
+    message_digest, signature_advice_entry, PublicKey, SecretKey, Signature,
+};
+
+// ECDSA SECP256K1
+// ================================================================================================
+
+fn secret_key(scalar: u8) -> SecretKey {
+    let mut bytes = [0_u8; 32];
+    bytes[31] = scalar;
+    SecretKey::from_bytes(&bytes).unwrap()
+}
+
+fn public_key(x: &str, y: &str) -> PublicKey {
+    PublicKey::new(hex_to_bytes(x).unwrap(), hex_to_bytes(y).unwrap())
+}
+
+#[test]
+fn test_ecdsa_secp256k1_public_key() {
+    // the public keys of the secret keys 1, 2 and 3 are G, 2G and 3G respectively
+    assert_eq!(
+        secret_key(1).public_key(),
+        public_key(
+            "0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        )
+    );
+    assert_eq!(
+        secret_key(2).public_key(),
+        public_key(
+            "0xc6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            "0x1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+        )
+    );
+    assert_eq!(
+        secret_key(3).public_key(),
+        public_key(
+            "0xf9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "0x388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+        )
+    );
+}
+
+#[test]
+fn test_ecdsa_secp256k1_secret_key_range() {
+    assert!(SecretKey::from_bytes(&[0_u8; 32]).is_none());
+
+    // the order of the secp256k1 curve is not a valid secret key, but the order minus one is
+    let order: [u8; 32] =
+        hex_to_bytes("0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141").unwrap();
+    assert!(SecretKey::from_bytes(&order).is_none());
+
+    let mut max_key = order;
+    max_key[31] -= 1;
+    assert_eq!(SecretKey::from_bytes(&max_key).unwrap().to_bytes(), max_key);
+}
+
+#[test]
+fn test_ecdsa_secp256k1_sign_and_verify() {
+    let key = SecretKey::from_bytes(&[7_u8; 32]).unwrap();
+    let pub_key = key.public_key();
+    let message: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(u64::MAX - 1)];
+
+    // signing is deterministic and the signature verifies against the public key
+    let signature = key.sign(message);
+    assert_eq!(signature, key.sign(message));
+    assert!(pub_key.verify(message, &signature));
+
+    // the signature does not verify for a different message or a different public key
+    assert!(!pub_key.verify([ZERO, Felt::new(2), Felt::new(3), Felt::new(4)], &signature));
+    assert!(!secret_key(2).public_key().verify(message, &signature));
+
+    // a different key produces a different signature for the same message
+    let other_signature = secret_key(2).sign(message);
+    assert_ne!(signature, other_signature);
+    assert!(secret_key(2).public_key().verify(message, &other_signature));
+}
+
+// ECDSA SECP256K1 VERIFICATION IN THE VM
+// ================================================================================================
+
+#[test]
+fn test_ecdsa_secp256k1_message_to_montgomery_in_vm() {
+    // every element of a message is smaller than 2^64 - 2^32 + 1, and thus even the digest of the
+    // largest message is smaller than the order of the secp256k1 curve
+    let max_message: Word = [-ONE; 4];
+    let order: [u8; 32] =
+        hex_to_bytes("0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141").unwrap();
+    assert!(message_digest(max_message) < order);
+
+    for message in [
+        [ZERO; 4],
+        [ONE, Felt::new(2), Felt::new(3), Felt::new(u64::MAX - 1)],
+        max_message,
+    ] {
+        let code = format!(
+            "
+            use.miden::contracts::auth::ecdsa_secp256k1
+
+            begin
+                push.{message}
+                exec.ecdsa_secp256k1::message_to_montgomery
+                mem_storew.1000 dropw mem_storew.1001 dropw
+            end
+            ",
+            message = prepare_word(&message),
+        );
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let process = run_within_host("", &code, StackInputs::default(), host, None).unwrap();
+
+        // the components of a signature are converted into Montgomery form in the same way as the
+        // message digest, and are laid out in memory in the same way
+        let expected = Signature::new(message_digest(message), [0_u8; 32]).to_elements();
+        assert_eq!(process.get_mem_value(ContextId::root(), 1000).unwrap(), expected[..4]);
+        assert_eq!(process.get_mem_value(ContextId::root(), 1001).unwrap(), expected[4..8]);
+    }
+}
+
+#[test]
+fn test_ecdsa_secp256k1_verify_signature_with_invalid_public_key_in_vm() {
+    let key = SecretKey::from_bytes(&[7_u8; 32]).unwrap();
+    let message: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let signature = key.sign(message);
+
+    // the public key provided via the advice map does not match the commitment on the stack
+    let (advice_key, _) = signature_advice_entry(&key.public_key(), message, &signature);
+    let (_, advice_values) =
+        signature_advice_entry(&secret_key(2).public_key(), message, &signature);
+
+    assert!(
+        verify_signature_in_vm(key.public_key().into(), message, (advice_key, advice_values))
+            .is_err()
+    );
+}
+
+#[test]
+#[ignore]
+/// Verifying an ECDSA signature in the VM takes more than 8 million cycles, and recording the
+/// execution trace of the verification requires more than 5 GB of memory, so this test is ignored
+/// by default. The conversion of the message and the loading of the public key are covered by the
+/// tests above.
+fn test_ecdsa_secp256k1_verify_signature_in_vm() {
+    let key = SecretKey::from_bytes(&[7_u8; 32]).unwrap();
+    let message: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(u64::MAX - 1)];
+    let signature = key.sign(message);
+
+    let advice_entry = signature_advice_entry(&key.public_key(), message, &signature);
+    assert!(verify_signature_in_vm(key.public_key().into(), message, advice_entry).is_ok());
+}
+
+/// Verifies the signature provided via the specified advice map entry against the public key
+/// commitment and the message using the `verify_signature` procedure of the
+/// `miden::contracts::auth::ecdsa_secp256k1` module.
+fn verify_signature_in_vm(
+    pub_key: Word,
+    message: Word,
+    advice_entry: (Digest, Vec<Felt>),
+) -> Result<(), ExecutionError> {
+    let advice_inputs = AdviceInputs::default().with_map([advice_entry]);
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+
+    let code = format!(
+        "
+        use.miden::contracts::auth::ecdsa_secp256k1
+
+        begin
+            push.{message} push.{pub_key}
+            exec.ecdsa_secp256k1::verify_signature
+        end
+        ",
+        message = prepare_word(&message),
+        pub_key = prepare_word(&pub_key),
+    );
+
+    run_within_host("", &code, StackInputs::default(), host, None).map(|_| ())
+}
@@ -5,12 +5,12 @@ use mock::{
 };
 
 use super::{
-    build_module_path, ContextId, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
+    build_module_path, ContextId, Felt, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
 };
 use crate::transaction::{
     memory::{CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE},
-    ToTransactionKernelInputs, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    ToTransactionKernelInputs, EXPIRATION_BLOCK_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
 const EPILOGUE_FILE: &str = "epilogue.masm";
@@ -68,11 +68,14 @@ fn test_epilogue() {
         executed_transaction.final_account().hash().as_elements(),
     );
 
+    // assert the transaction does not expire by default
+    assert_eq!(process.stack.get(EXPIRATION_BLOCK_ELEMENT_IDX), Felt::from(u32::MAX));
+
     // assert stack has been truncated correctly
     assert_eq!(process.stack.depth(), 16);
 
     // assert the bottom of the stack is filled with zeros
-    for i in 12..16 {
+    for i in 13..16 {
         assert_eq!(process.stack.get(i), ZERO);
     }
 }
@@ -1,8 +1,14 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    accounts::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-    notes::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteType},
+    accounts::{
+        AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+    },
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    notes::{
+        Note, NoteAssets, NoteEnvelope, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteType,
+    },
     transaction::{OutputNote, OutputNotes},
     Word, ONE, ZERO,
 };
@@ -20,7 +26,7 @@ use super::{ContextId, Felt, MemAdviceProvider, Process, ProcessState, StackInpu
 use crate::transaction::memory::{
     CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
     CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE,
-    NUM_CREATED_NOTES_PTR,
+    NUM_CREATED_NOTES_PTR, TX_EXPIRATION_BLOCK_NUM_PTR,
 };
 
 #[test]
@@ -170,6 +176,164 @@ fn test_create_note_too_many_notes() {
     assert!(process.is_err());
 }
 
+#[test]
+fn test_add_asset_to_note() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let account_id = tx_inputs.account().id();
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let faucet_id_3 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    let asset_1: Asset = FungibleAsset::new(faucet_id_1, 10).unwrap().into();
+    let asset_2: Asset = FungibleAsset::new(faucet_id_2, 20).unwrap().into();
+    let asset_3: Asset = FungibleAsset::new(faucet_id_1, 5).unwrap().into();
+    let asset_4: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_3, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+
+    let code = format!(
+        "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        push.{recipient}
+        push.{OFFCHAIN_NOTE}
+        push.{tag}
+        push.{asset_1}
+        exec.tx::create_note
+        # => [note_ptr]
+
+        push.{asset_2} movup.4 exec.tx::add_asset_to_note
+        push.{asset_3} movup.4 exec.tx::add_asset_to_note
+        push.{asset_4} movup.4 exec.tx::add_asset_to_note
+        # => [note_ptr]
+
+        exec.tx::get_output_notes_hash
+        # => [COM, note_ptr]
+    end
+    ",
+        recipient = prepare_word(&recipient),
+        OFFCHAIN_NOTE = NoteType::OffChain as u8,
+        tag = tag,
+        asset_1 = prepare_word(&asset_1.into()),
+        asset_2 = prepare_word(&asset_2.into()),
+        asset_3 = prepare_word(&asset_3.into()),
+        asset_4 = prepare_word(&asset_4.into()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    // the fungible assets issued by the same faucet are merged
+    let expected_assets: [Asset; 3] =
+        [FungibleAsset::new(faucet_id_1, 15).unwrap().into(), asset_2, asset_4];
+
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_NUM_ASSETS_OFFSET),
+        [Felt::new(3), ZERO, ZERO, ZERO],
+        "number of assets must be stored at the correct memory location",
+    );
+
+    for (i, asset) in expected_assets.iter().enumerate() {
+        assert_eq!(
+            read_root_mem_value(
+                &process,
+                CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET + i as u32
+            ),
+            Word::from(*asset),
+            "asset must be stored at the correct memory location",
+        );
+    }
+
+    // the output notes hash must commit to all assets of the note
+    let assets = NoteAssets::new(expected_assets.to_vec()).unwrap();
+    let metadata =
+        NoteMetadata::new(account_id, NoteType::OffChain, tag.try_into().unwrap(), ZERO).unwrap();
+    let note_id = NoteId::new(recipient.into(), assets.commitment());
+    let expected_output_notes_hash =
+        OutputNotes::new(vec![OutputNote::Private(NoteEnvelope::new(note_id, metadata).unwrap())])
+            .unwrap()
+            .commitment();
+
+    assert_eq!(process.get_stack_word(0), *expected_output_notes_hash);
+    assert_eq!(process.stack.get(4), Felt::from(CREATED_NOTE_SECTION_OFFSET));
+}
+
+#[test]
+fn test_add_asset_to_note_fails() {
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            vec![1, 2, 3, 4],
+        )
+        .unwrap(),
+    )
+    .unwrap()
+    .into();
+    let fungible_asset: Asset = FungibleAsset::new(
+        AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+        FungibleAsset::MAX_AMOUNT,
+    )
+    .unwrap()
+    .into();
+
+    let run_add_asset = |asset: Asset, ptr_offset: u32, added_asset: Asset| {
+        let (tx_inputs, tx_args) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.{OFFCHAIN_NOTE}
+            push.4
+            push.{asset}
+            exec.tx::create_note
+            # => [note_ptr]
+
+            add.{ptr_offset}
+            push.{added_asset} movup.4 exec.tx::add_asset_to_note
+        end
+        ",
+            recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+            OFFCHAIN_NOTE = NoteType::OffChain as u8,
+            asset = prepare_word(&asset.into()),
+            added_asset = prepare_word(&added_asset.into()),
+        );
+
+        let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+        run_tx(&transaction)
+    };
+
+    // sanity check: adding the asset to an existing note succeeds
+    assert!(run_add_asset(non_fungible_asset, 0, fungible_asset).is_ok());
+
+    // the note pointer must point to the beginning of a created note
+    assert!(run_add_asset(non_fungible_asset, 1, fungible_asset).is_err());
+    assert!(run_add_asset(non_fungible_asset, NOTE_MEM_SIZE, fungible_asset).is_err());
+
+    // the same non-fungible asset cannot be added to the note twice
+    assert!(run_add_asset(non_fungible_asset, 0, non_fungible_asset).is_err());
+
+    // the amount of a fungible asset cannot exceed the maximum amount
+    assert!(run_add_asset(fungible_asset, 0, fungible_asset).is_err());
+}
+
 #[test]
 fn test_get_output_notes_hash() {
     let (tx_inputs, tx_args) =
@@ -287,6 +451,84 @@ fn test_get_output_notes_hash() {
     assert_eq!(process.get_stack_word(0), *expected_output_notes_hash);
 }
 
+#[test]
+fn test_get_block_timestamp() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_block_timestamp
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs.clone(), tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.stack.get(0), tx_inputs.block_header().timestamp());
+}
+
+#[test]
+fn test_update_expiration_block_num() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        # by default the transaction does not expire
+        exec.tx::get_expiration_delta assertz
+
+        # the expiration can be brought forward but not postponed
+        push.10 exec.tx::update_expiration_block_num
+        push.5 exec.tx::update_expiration_block_num
+        push.20 exec.tx::update_expiration_block_num
+
+        exec.tx::get_expiration_delta
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs.clone(), tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    let block_num = tx_inputs.block_header().block_num();
+    assert_eq!(process.stack.get(0), Felt::from(5_u32));
+    assert_eq!(
+        read_root_mem_value(&process, TX_EXPIRATION_BLOCK_NUM_PTR)[0],
+        Felt::from(block_num + 5)
+    );
+}
+
+#[test]
+fn test_update_expiration_block_num_invalid_delta() {
+    for delta in [0, 0x10000] {
+        let (tx_inputs, tx_args) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{delta} exec.tx::update_expiration_block_num
+        end
+        "
+        );
+
+        let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+        let process = run_tx(&transaction);
+
+        assert!(process.is_err(), "expiration delta {delta} should be rejected");
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
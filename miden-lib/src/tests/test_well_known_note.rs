@@ -0,0 +1,206 @@
+use miden_objects::{
+    accounts::{
+        AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::RpoRandomCoin,
+    notes::{
+        Note, NoteAssets, NoteExecutionMode, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
+        NoteType,
+    },
+    Hasher, NoteError,
+};
+
+use super::{Felt, ZERO};
+use crate::notes::{
+    create_htlc_note, create_p2id_note, create_p2idr_note, create_p2idt_note,
+    create_partial_swap_note, create_swap_note, utils::build_p2id_recipient, HtlcNoteInputs,
+    P2idNoteInputs, P2idrNoteInputs, P2idtNoteInputs, SwapNoteInputs, WellKnownNote,
+    WellKnownNoteInputs,
+};
+
+#[test]
+fn test_parse_well_known_notes() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let requested_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // P2ID
+    let note =
+        create_p2id_note(sender, target, vec![offered_asset], NoteType::Public, rng()).unwrap();
+    assert_eq!(
+        WellKnownNote::parse_note(&note).unwrap(),
+        Some((WellKnownNote::P2ID, WellKnownNoteInputs::P2ID(P2idNoteInputs { target })))
+    );
+
+    // P2IDR
+    let note = create_p2idr_note(sender, target, vec![offered_asset], NoteType::Public, 42, rng())
+        .unwrap();
+    assert_eq!(
+        WellKnownNote::parse_note(&note).unwrap(),
+        Some((
+            WellKnownNote::P2IDR,
+            WellKnownNoteInputs::P2IDR(P2idrNoteInputs { target, recall_height: 42 })
+        ))
+    );
+
+    // P2IDT
+    let note =
+        create_p2idt_note(sender, target, vec![offered_asset], NoteType::Public, 10, 20, rng())
+            .unwrap();
+    assert_eq!(
+        WellKnownNote::parse_note(&note).unwrap(),
+        Some((
+            WellKnownNote::P2IDT,
+            WellKnownNoteInputs::P2IDT(P2idtNoteInputs {
+                target,
+                unlock_timestamp: 10,
+                reclaim_timestamp: 20
+            })
+        ))
+    );
+
+    // HTLC
+    let secret_hash = Hasher::hash_elements(&[Felt::new(1), Felt::new(2), Felt::new(3), ZERO]);
+    let note = create_htlc_note(
+        sender,
+        target,
+        vec![offered_asset],
+        NoteType::Public,
+        secret_hash,
+        42,
+        rng(),
+    )
+    .unwrap();
+    assert_eq!(
+        WellKnownNote::parse_note(&note).unwrap(),
+        Some((
+            WellKnownNote::HTLC,
+            WellKnownNoteInputs::HTLC(HtlcNoteInputs { secret_hash, target, recall_height: 42 })
+        ))
+    );
+
+    // SWAP
+    let (note, payback_note) = create_swap_note(
+        sender,
+        offered_asset,
+        requested_asset,
+        NoteType::Public,
+        NoteType::OffChain,
+        rng(),
+    )
+    .unwrap();
+    let expected_inputs = SwapNoteInputs {
+        payback_recipient: build_p2id_recipient(sender, payback_note.serial_num()).unwrap(),
+        requested_asset,
+        payback_tag: NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap(),
+        payback_note_type: NoteType::OffChain,
+    };
+    assert_eq!(
+        WellKnownNote::parse_note(&note).unwrap(),
+        Some((WellKnownNote::SWAP, WellKnownNoteInputs::SWAP(expected_inputs)))
+    );
+    assert_eq!(payback_note.recipient().digest(), expected_inputs.payback_recipient);
+
+    // PSWAP
+    let note = create_partial_swap_note(
+        sender,
+        offered_asset,
+        FungibleAsset::new(faucet_id, 50).unwrap().into(),
+        NoteType::Public,
+        NoteTag::from_account_id(faucet_id, NoteExecutionMode::Local).unwrap(),
+        rng(),
+    )
+    .unwrap();
+    let (well_known_note, inputs) = WellKnownNote::parse_note(&note).unwrap().unwrap();
+    assert_eq!(well_known_note, WellKnownNote::PSWAP);
+    match inputs {
+        WellKnownNoteInputs::PSWAP(inputs) => {
+            assert_eq!(inputs.creator, sender);
+            assert_eq!(inputs.payback_script_root, WellKnownNote::P2ID.script_root());
+            assert_eq!(inputs.remainder_tag, note.metadata().tag());
+            assert_eq!(inputs.to_note_inputs().unwrap().commitment(), note.inputs().commitment());
+        },
+        _ => panic!("expected PSWAP note inputs"),
+    }
+}
+
+#[test]
+fn test_well_known_note_script_roots() {
+    for note in WellKnownNote::ALL {
+        // the roots computed by the build script match the roots of the compiled scripts
+        assert_eq!(note.script_root(), note.script().hash());
+        assert_eq!(WellKnownNote::from_script_root(note.script_root()), Some(note));
+    }
+}
+
+#[test]
+fn test_parse_unknown_and_malformed_notes() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // a P2ID note without inputs is recognized, but its inputs can not be parsed
+    let inputs = NoteInputs::new(vec![]).unwrap();
+    let note = build_note(sender, asset, WellKnownNote::P2ID, inputs);
+    assert_eq!(WellKnownNote::from_note(&note), Some(WellKnownNote::P2ID));
+    assert_eq!(
+        WellKnownNote::parse_note(&note),
+        Err(NoteError::InvalidNoteInputsLen { expected: 1, actual: 0 })
+    );
+
+    // a P2IDR note with a recall height which does not fit into u32
+    let inputs = NoteInputs::new(vec![sender.into(), Felt::new(u64::from(u32::MAX) + 1)]).unwrap();
+    let note = build_note(sender, asset, WellKnownNote::P2IDR, inputs);
+    assert_eq!(
+        WellKnownNote::parse_note(&note),
+        Err(NoteError::InvalidRecallHeight(u64::from(u32::MAX) + 1))
+    );
+
+    // a P2IDT note with an unlock timestamp which does not fit into u32
+    let inputs =
+        NoteInputs::new(vec![sender.into(), Felt::new(u64::from(u32::MAX) + 1), ZERO]).unwrap();
+    let note = build_note(sender, asset, WellKnownNote::P2IDT, inputs);
+    assert_eq!(
+        WellKnownNote::parse_note(&note),
+        Err(NoteError::InvalidNoteTimestamp(u64::from(u32::MAX) + 1))
+    );
+
+    // a P2ID note targeting an invalid account ID
+    let inputs = NoteInputs::new(vec![ZERO]).unwrap();
+    let note = build_note(sender, asset, WellKnownNote::P2ID, inputs);
+    assert!(matches!(
+        WellKnownNote::parse_note(&note),
+        Err(NoteError::InvalidAccountIdInput(_))
+    ));
+
+    // an arbitrary script is not recognized
+    assert_eq!(WellKnownNote::from_script_root(Default::default()), None);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn rng() -> RpoRandomCoin {
+    RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+}
+
+fn build_note(
+    sender: AccountId,
+    asset: Asset,
+    well_known_note: WellKnownNote,
+    inputs: NoteInputs,
+) -> Note {
+    let metadata = NoteMetadata::new(sender, NoteType::Public, 0.into(), ZERO).unwrap();
+    let assets = NoteAssets::new(vec![asset]).unwrap();
+    let recipient = NoteRecipient::new(Default::default(), well_known_note.script(), inputs);
+    Note::new(assets, metadata, recipient)
+}
@@ -12,6 +12,7 @@ use miden_objects::{
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionKernelError {
+    FailedToAddAssetToNote(NoteError),
     InvalidStorageSlotIndex(u64),
     MalformedAccountId(AccountError),
     MalformedAsset(AssetError),
@@ -22,6 +23,7 @@ pub enum TransactionKernelError {
     MalformedNoteType(NoteError),
     MalformedRecipientData(Vec<Felt>),
     MalformedTag(Felt),
+    MissingCreatedNote(u64),
     MissingNoteDetails(NoteMetadata, NoteAssets, Digest),
     MissingStorageSlotValue(u8, String),
     UnknownAccountProcedure(Digest),
@@ -30,6 +32,9 @@ pub enum TransactionKernelError {
 impl fmt::Display for TransactionKernelError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionKernelError::FailedToAddAssetToNote(err) => {
+                write!(f, "failed to add asset to note: {err}")
+            },
             TransactionKernelError::InvalidStorageSlotIndex(index) => {
                 let num_slots = AccountStorage::NUM_STORAGE_SLOTS;
                 write!(f, "storage slot index {index} is invalid, must be smaller than {num_slots}")
@@ -65,6 +70,9 @@ impl fmt::Display for TransactionKernelError {
                     tag
                 )
             },
+            TransactionKernelError::MissingCreatedNote(note_idx) => {
+                write!(f, "note with index {note_idx} has not been created")
+            },
             TransactionKernelError::MissingNoteDetails(metadata, vault, recipient) => {
                 write!( f, "Public note missing the details in the advice provider. metadata: {:?} vault: {:?} recipient: {:?}", metadata, vault, recipient)
             },
@@ -105,3 +113,49 @@ impl fmt::Display for TransactionEventParsingError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionEventParsingError {}
+
+// TRANSACTION TRACE PARSING ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TransactionTraceParsingError {
+    InvalidTransactionTrace(u32),
+    NotTransactionTrace(u32),
+}
+
+impl fmt::Display for TransactionTraceParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTransactionTrace(trace_id) => {
+                write!(f, "trace {trace_id} is not a valid transaction kernel trace")
+            },
+            Self::NotTransactionTrace(trace_id) => {
+                write!(f, "trace {trace_id} is not a transaction kernel trace")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionTraceParsingError {}
+
+// KERNEL ASSERTION ERROR
+// ================================================================================================
+
+include!(concat!(env!("OUT_DIR"), "/kernel_errors.rs"));
+
+impl KernelAssertionError {
+    /// Returns the code of this error.
+    pub fn code(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl fmt::Display for KernelAssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:#010x}): {}", self.name(), self.code(), self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KernelAssertionError {}
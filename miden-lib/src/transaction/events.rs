@@ -1,6 +1,6 @@
 use core::fmt;
 
-use super::TransactionEventParsingError;
+use super::{TransactionEventParsingError, TransactionTraceParsingError};
 
 // TRANSACTION EVENT
 // ================================================================================================
@@ -11,6 +11,9 @@ const ACCOUNT_STORAGE_SET_ITEM: u32 = 0x2_0002; // 131074
 const ACCOUNT_INCREMENT_NONCE: u32 = 0x2_0003; // 131075
 const ACCOUNT_PUSH_PROCEDURE_INDEX: u32 = 0x2_0004; // 131076
 const NOTE_CREATED: u32 = 0x2_0005; // 131077
+const ACCOUNT_STORAGE_SET_MAP_ITEM: u32 = 0x2_0006; // 131078
+const ACCOUNT_STORAGE_SET_ARRAY_ITEM: u32 = 0x2_0007; // 131079
+const NOTE_ADD_ASSET: u32 = 0x2_0008; // 131080
 
 /// Events which may be emitted by a transaction kernel.
 ///
@@ -28,6 +31,9 @@ pub enum TransactionEvent {
     AccountIncrementNonce = ACCOUNT_INCREMENT_NONCE,
     AccountPushProcedureIndex = ACCOUNT_PUSH_PROCEDURE_INDEX,
     NoteCreated = NOTE_CREATED,
+    AccountStorageSetMapItem = ACCOUNT_STORAGE_SET_MAP_ITEM,
+    AccountStorageSetArrayItem = ACCOUNT_STORAGE_SET_ARRAY_ITEM,
+    NoteAddAsset = NOTE_ADD_ASSET,
 }
 
 impl TransactionEvent {
@@ -56,7 +62,81 @@ impl TryFrom<u32> for TransactionEvent {
             ACCOUNT_INCREMENT_NONCE => Ok(TransactionEvent::AccountIncrementNonce),
             ACCOUNT_PUSH_PROCEDURE_INDEX => Ok(TransactionEvent::AccountPushProcedureIndex),
             NOTE_CREATED => Ok(TransactionEvent::NoteCreated),
+            ACCOUNT_STORAGE_SET_MAP_ITEM => Ok(TransactionEvent::AccountStorageSetMapItem),
+            ACCOUNT_STORAGE_SET_ARRAY_ITEM => Ok(TransactionEvent::AccountStorageSetArrayItem),
+            NOTE_ADD_ASSET => Ok(TransactionEvent::NoteAddAsset),
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
 }
+
+// TRANSACTION TRACE
+// ================================================================================================
+
+const PROLOGUE_START: u32 = 0x2_0000; // 131072
+const PROLOGUE_END: u32 = 0x2_0001; // 131073
+const NOTES_PROCESSING_START: u32 = 0x2_0002; // 131074
+const NOTES_PROCESSING_END: u32 = 0x2_0003; // 131075
+const NOTE_EXECUTION_START: u32 = 0x2_0004; // 131076
+const NOTE_EXECUTION_END: u32 = 0x2_0005; // 131077
+const TX_SCRIPT_PROCESSING_START: u32 = 0x2_0006; // 131078
+const TX_SCRIPT_PROCESSING_END: u32 = 0x2_0007; // 131079
+const EPILOGUE_START: u32 = 0x2_0008; // 131080
+const EPILOGUE_END: u32 = 0x2_0009; // 131081
+
+/// Traces which may be emitted by a transaction kernel.
+///
+/// The traces are emitted via the `trace.<trace_id>` instruction and mark the boundaries of the
+/// transaction kernel phases. They are handled by the host only when tracing is enabled in the
+/// execution options. Trace IDs follow the same structure as event IDs:
+/// - The upper 16 bits of the trace ID are set to 2.
+/// - The lower 16 bits represent a unique trace ID within the transaction kernel.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionTrace {
+    PrologueStart = PROLOGUE_START,
+    PrologueEnd = PROLOGUE_END,
+    NotesProcessingStart = NOTES_PROCESSING_START,
+    NotesProcessingEnd = NOTES_PROCESSING_END,
+    NoteExecutionStart = NOTE_EXECUTION_START,
+    NoteExecutionEnd = NOTE_EXECUTION_END,
+    TxScriptProcessingStart = TX_SCRIPT_PROCESSING_START,
+    TxScriptProcessingEnd = TX_SCRIPT_PROCESSING_END,
+    EpilogueStart = EPILOGUE_START,
+    EpilogueEnd = EPILOGUE_END,
+}
+
+impl TransactionTrace {
+    /// Value of the top 16 bits of a transaction kernel trace ID.
+    pub const TRACE_ID_PREFIX: u16 = 2;
+}
+
+impl fmt::Display for TransactionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl TryFrom<u32> for TransactionTrace {
+    type Error = TransactionTraceParsingError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value >> 16 != Self::TRACE_ID_PREFIX as u32 {
+            return Err(TransactionTraceParsingError::NotTransactionTrace(value));
+        }
+
+        match value {
+            PROLOGUE_START => Ok(TransactionTrace::PrologueStart),
+            PROLOGUE_END => Ok(TransactionTrace::PrologueEnd),
+            NOTES_PROCESSING_START => Ok(TransactionTrace::NotesProcessingStart),
+            NOTES_PROCESSING_END => Ok(TransactionTrace::NotesProcessingEnd),
+            NOTE_EXECUTION_START => Ok(TransactionTrace::NoteExecutionStart),
+            NOTE_EXECUTION_END => Ok(TransactionTrace::NoteExecutionEnd),
+            TX_SCRIPT_PROCESSING_START => Ok(TransactionTrace::TxScriptProcessingStart),
+            TX_SCRIPT_PROCESSING_END => Ok(TransactionTrace::TxScriptProcessingEnd),
+            EPILOGUE_START => Ok(TransactionTrace::EpilogueStart),
+            EPILOGUE_END => Ok(TransactionTrace::EpilogueEnd),
+            _ => Err(TransactionTraceParsingError::InvalidTransactionTrace(value)),
+        }
+    }
+}
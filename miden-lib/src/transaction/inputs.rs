@@ -214,6 +214,17 @@ fn add_account_to_advice_inputs(
         storage.layout().iter().map(Felt::from).collect(),
     )]);
 
+    // extend the merkle store and advice map with the contents of storage maps
+    for map in storage.maps().values() {
+        inputs.extend_merkle_store(map.inner_nodes());
+        inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+    }
+
+    // extend the merkle store with the contents of storage arrays
+    for array in storage.arrays().values() {
+        inputs.extend_merkle_store(array.inner_nodes());
+    }
+
     // --- account vault ------------------------------------------------------
     let vault = account.vault();
 
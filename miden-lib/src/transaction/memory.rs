@@ -39,6 +39,9 @@ pub const INPUT_VAULT_ROOT_PTR: MemoryAddress = 3;
 /// The memory address at which the output vault root is stored
 pub const OUTPUT_VAULT_ROOT_PTR: MemoryAddress = 4;
 
+/// The memory address at which the transaction expiration block number is stored.
+pub const TX_EXPIRATION_BLOCK_NUM_PTR: MemoryAddress = 5;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -15,19 +15,22 @@ use super::MidenLib;
 pub mod memory;
 
 mod events;
-pub use events::TransactionEvent;
+pub use events::{TransactionEvent, TransactionTrace};
 
 mod inputs;
 pub use inputs::ToTransactionKernelInputs;
 
 mod outputs;
 pub use outputs::{
-    parse_final_account_stub, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    parse_final_account_stub, EXPIRATION_BLOCK_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
 mod errors;
-pub use errors::{TransactionEventParsingError, TransactionKernelError};
+pub use errors::{
+    KernelAssertionError, TransactionEventParsingError, TransactionKernelError,
+    TransactionTraceParsingError,
+};
 
 // TRANSACTION KERNEL
 // ================================================================================================
@@ -116,12 +119,26 @@ impl TransactionKernel {
             .expect("Invalid stack input")
     }
 
+    /// Returns the output stack of the transaction kernel.
+    ///
+    /// The output stack is arranged as follows:
+    ///
+    /// Stack: [TXSR, CNC, FAH, tx_expiration_block_num]
+    ///
+    /// Where:
+    /// - TXSR is the transaction script root.
+    /// - CNC is the commitment to the notes created by the transaction.
+    /// - FAH is the final account hash of the account that the transaction is being
+    ///   executed against.
+    /// - tx_expiration_block_num is the number of the block at which the transaction expires.
     pub fn build_output_stack(
         final_acct_hash: Digest,
         output_notes_hash: Digest,
         tx_script_root: Option<Digest>,
+        tx_expiration_block_num: u32,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
+        let mut outputs: Vec<Felt> = Vec::with_capacity(13);
+        outputs.push(Felt::from(tx_expiration_block_num));
         outputs.extend(final_acct_hash);
         outputs.extend(output_notes_hash);
         outputs.extend(tx_script_root.unwrap_or_default());
@@ -135,20 +152,33 @@ impl TransactionKernel {
     ///
     /// The data on the stack is expected to be arranged as follows:
     ///
-    /// Stack: [TXSR, CNC, FAH]
+    /// Stack: [TXSR, CNC, FAH, tx_expiration_block_num]
     ///
     /// Where:
     /// - TXSR is the transaction script root.
     /// - CNC is the commitment to the notes created by the transaction.
     /// - FAH is the final account hash of the account that the transaction is being
     ///   executed against.
-    pub fn parse_output_stack(stack: &StackOutputs) -> (Digest, Digest, Digest) {
+    /// - tx_expiration_block_num is the number of the block at which the transaction expires.
+    ///
+    /// # Errors
+    /// Returns an error if the expiration block number is not a valid u32 value.
+    pub fn parse_output_stack(
+        stack: &StackOutputs,
+    ) -> Result<(Digest, Digest, Digest, u32), TransactionOutputError> {
         // TODO: use constants
         let tx_script_root = stack.get_stack_word(0).expect("first word missing").into();
         let output_notes_hash = stack.get_stack_word(4).expect("second word missing").into();
         let final_account_hash = stack.get_stack_word(8).expect("third word missing").into();
-
-        (final_account_hash, output_notes_hash, tx_script_root)
+        let expiration_block_num = stack
+            .get_stack_item(EXPIRATION_BLOCK_ELEMENT_IDX)
+            .expect("expiration block num missing")
+            .as_int();
+        let expiration_block_num = u32::try_from(expiration_block_num).map_err(|_| {
+            TransactionOutputError::ExpirationBlockNumberInvalid(expiration_block_num)
+        })?;
+
+        Ok((final_account_hash, output_notes_hash, tx_script_root, expiration_block_num))
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -158,13 +188,14 @@ impl TransactionKernel {
     ///
     /// The output stack is expected to be arrange as follows:
     ///
-    /// Stack: [TXSR, CNC, FAH]
+    /// Stack: [TXSR, CNC, FAH, tx_expiration_block_num]
     ///
     /// Where:
     /// - TXSR is the transaction script root.
     /// - CNC is the commitment to the notes created by the transaction.
     /// - FAH is the final account hash of the account that the transaction is being
     ///   executed against.
+    /// - tx_expiration_block_num is the number of the block at which the transaction expires.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
     /// in the provided advice map under keys CNC and FAH.
@@ -173,7 +204,8 @@ impl TransactionKernel {
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash, _tx_script_root) = Self::parse_output_stack(stack);
+        let (final_acct_hash, output_notes_hash, _tx_script_root, expiration_block_num) =
+            Self::parse_output_stack(stack)?;
 
         // parse final account state
         let final_account_data: &[Word] = group_slice_elements(
@@ -193,6 +225,10 @@ impl TransactionKernel {
             ));
         }
 
-        Ok(TransactionOutputs { account, output_notes })
+        Ok(TransactionOutputs {
+            account,
+            output_notes,
+            expiration_block_num,
+        })
     }
 }
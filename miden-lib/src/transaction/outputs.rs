@@ -20,6 +20,10 @@ pub const OUTPUT_NOTES_COMMITMENT_WORD_IDX: usize = 1;
 /// The index of the word at which the final account hash is stored on the output stack.
 pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 2;
 
+/// The index of the element at which the transaction expiration block number is stored on the
+/// output stack.
+pub const EXPIRATION_BLOCK_ELEMENT_IDX: usize = 12;
+
 // ACCOUNT STUB EXTRACTOR
 // ================================================================================================
 
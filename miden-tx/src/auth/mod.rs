@@ -0,0 +1,131 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use miden_objects::{
+    crypto::{
+        dsa::rpo_falcon512::{Polynomial, SecretKey},
+        rand::FeltRng,
+    },
+    Digest, Felt, Word,
+};
+
+use crate::error::AuthenticationError;
+
+// TRANSACTION AUTHENTICATOR
+// ================================================================================================
+
+/// Defines an authenticator for transactions.
+///
+/// The authenticator is used by the [TransactionExecutor](crate::TransactionExecutor) to sign
+/// messages during transaction execution. Whenever the transaction kernel requests a signature
+/// (e.g., from within the `auth_tx_rpo_falcon512` procedure) which is not already present in the
+/// advice map, the executor asks the authenticator to sign the exact message computed by the
+/// kernel. Thus, there is no need to execute a transaction twice in order to compute the message
+/// to be signed.
+pub trait TransactionAuthenticator {
+    /// Returns a signature of the provided message made with the secret key which corresponds to
+    /// the specified public key.
+    ///
+    /// The signature is returned as a vector of field elements in the order in which they are
+    /// expected to be pushed onto the advice stack by the signature verification procedure.
+    ///
+    /// # Errors
+    /// Returns an error if the authenticator does not hold the secret key for the specified public
+    /// key, or if it refuses to sign the message.
+    fn get_signature(&self, pub_key: Word, message: Word)
+        -> Result<Vec<Felt>, AuthenticationError>;
+}
+
+// BASIC AUTHENTICATOR
+// ================================================================================================
+
+/// An in-memory [TransactionAuthenticator] which signs messages with RPO Falcon512 secret keys.
+///
+/// The authenticator signs any message requested with one of the keys it holds, and thus it
+/// should be used only when the party executing the transaction is trusted with the keys.
+///
+/// When the `std` feature is enabled, the randomness generator is guarded by a mutex, and thus the
+/// authenticator can be shared across threads.
+pub struct BasicAuthenticator<R> {
+    /// Secret keys held by this authenticator mapped by the commitments to their public keys.
+    keys: BTreeMap<Digest, SecretKey>,
+    /// Randomness generator used to sample signature nonces.
+    #[cfg(feature = "std")]
+    rng: Mutex<R>,
+    #[cfg(not(feature = "std"))]
+    rng: RefCell<R>,
+}
+
+impl<R: FeltRng> BasicAuthenticator<R> {
+    /// Returns a new [BasicAuthenticator] holding the provided secret keys and using the provided
+    /// randomness generator to generate signatures.
+    pub fn new(keys: &[SecretKey], rng: R) -> Self {
+        let keys = keys
+            .iter()
+            .map(|key| (Word::from(key.public_key()).into(), key.clone()))
+            .collect();
+
+        #[cfg(feature = "std")]
+        let rng = Mutex::new(rng);
+        #[cfg(not(feature = "std"))]
+        let rng = RefCell::new(rng);
+
+        Self { keys, rng }
+    }
+
+    /// Adds the provided secret key to this authenticator.
+    pub fn add_key(&mut self, key: SecretKey) {
+        self.keys.insert(Word::from(key.public_key()).into(), key);
+    }
+}
+
+impl<R: FeltRng> TransactionAuthenticator for BasicAuthenticator<R> {
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let key = self
+            .keys
+            .get(&Digest::from(pub_key))
+            .ok_or(AuthenticationError::UnknownKey(pub_key.into()))?;
+
+        #[cfg(feature = "std")]
+        let mut rng = self.rng.lock().map_err(|_| {
+            AuthenticationError::InternalError("randomness generator lock is poisoned".into())
+        })?;
+        #[cfg(not(feature = "std"))]
+        let mut rng = self.rng.borrow_mut();
+
+        Ok(get_falcon_signature(key, message, &mut *rng))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Signs the provided message with the provided RPO Falcon512 secret key and returns the signature
+/// as expected by the `rpo_falcon512::verify` procedure of the Miden standard library.
+///
+/// The returned vector contains the nonce of the signature, the expanded public key, the signature
+/// polynomial, and the product of the expanded public key and the signature polynomial. The
+/// elements are arranged in reverse order so that pushing them onto the advice stack one by one
+/// puts the first nonce element on top of the stack.
+pub fn get_falcon_signature<R: FeltRng>(key: &SecretKey, message: Word, rng: &mut R) -> Vec<Felt> {
+    let signature = key.sign_with_rng(message, rng);
+
+    let nonce = signature.nonce();
+    let s2 = signature.sig_poly();
+    let h = key.compute_pub_key_poly().0;
+    let pi = Polynomial::mul_modulo_p(&h, s2);
+
+    let mut result: Vec<Felt> = nonce.to_elements().to_vec();
+    result.extend(h.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
+    result.extend(s2.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
+    result.extend(pi.iter().map(|a| Felt::new(*a)));
+    result.reverse();
+    result
+}
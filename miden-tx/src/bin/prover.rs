@@ -0,0 +1,54 @@
+//! Standalone transaction prover which proves transactions on behalf of
+//! [RemoteTransactionProver](miden_tx::RemoteTransactionProver)s.
+//!
+//! Usage: `miden-tx-prover [--address <ADDRESS>] [--security <96|128>] [--recursive]`
+
+use std::{env, process};
+
+use miden_tx::{ProvingOptions, TransactionProverService};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:50051";
+const USAGE: &str =
+    "Usage: miden-tx-prover [--address <ADDRESS>] [--security <96|128>] [--recursive]";
+
+fn main() {
+    let mut address = String::from(DEFAULT_ADDRESS);
+    let mut security = 96;
+    let mut recursive = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--address" => address = args.next().unwrap_or_else(|| exit_with_usage()),
+            "--security" => {
+                security = match args.next().as_deref() {
+                    Some("96") => 96,
+                    Some("128") => 128,
+                    _ => exit_with_usage(),
+                }
+            },
+            "--recursive" => recursive = true,
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return;
+            },
+            _ => exit_with_usage(),
+        }
+    }
+
+    let proof_options = match security {
+        96 => ProvingOptions::with_96_bit_security(recursive),
+        _ => ProvingOptions::with_128_bit_security(recursive),
+    };
+
+    println!("Proving transactions at {address} with {security}-bit security");
+    if let Err(err) = TransactionProverService::new(proof_options).bind_and_serve(&address) {
+        eprintln!("Transaction prover failed: {err}");
+        process::exit(1);
+    }
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
@@ -1,13 +1,17 @@
-use alloc::string::String;
+use alloc::{boxed::Box, string::String};
 use core::fmt::{self, Display};
 
+use miden_lib::transaction::KernelAssertionError;
 use miden_objects::{
     assembly::AssemblyError, notes::NoteId, Felt, NoteError, ProvenTransactionError,
     TransactionInputError, TransactionOutputError,
 };
 use miden_verifier::VerificationError;
 
-use super::{AccountError, AccountId, Digest, ExecutionError};
+use super::{
+    host::{TransactionMeasurements, TransactionProgress},
+    AccountError, AccountId, Digest, ExecutionError,
+};
 
 // TRANSACTION COMPILER ERROR
 // ================================================================================================
@@ -54,7 +58,36 @@ pub enum TransactionExecutorError {
         actual: Option<Felt>,
     },
     InvalidTransactionOutput(TransactionOutputError),
+    KernelAssertionFailed {
+        clk: u32,
+        error: KernelAssertionError,
+    },
     LoadAccountFailed(TransactionCompilerError),
+    MeasuredExecutionFailed {
+        error: Box<TransactionExecutorError>,
+        measurements: Box<TransactionMeasurements>,
+        progress: Box<TransactionProgress>,
+    },
+    SignatureGenerationFailed(AuthenticationError),
+}
+
+impl From<ExecutionError> for TransactionExecutorError {
+    /// Converts an [ExecutionError] into a [TransactionExecutorError].
+    ///
+    /// Failed assertions with error codes declared in the transaction kernel, the Miden library,
+    /// or the standard note scripts are converted into
+    /// [TransactionExecutorError::KernelAssertionFailed].
+    fn from(error: ExecutionError) -> Self {
+        match error {
+            ExecutionError::FailedAssertion { clk, err_code, .. } => {
+                match KernelAssertionError::from_code(err_code) {
+                    Some(kernel_error) => Self::KernelAssertionFailed { clk, error: kernel_error },
+                    None => Self::ExecuteTransactionProgramFailed(error),
+                }
+            },
+            error => Self::ExecuteTransactionProgramFailed(error),
+        }
+    }
 }
 
 impl fmt::Display for TransactionExecutorError {
@@ -71,15 +104,21 @@ impl std::error::Error for TransactionExecutorError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionProverError {
+    CompileTransactionFailed(TransactionCompilerError),
     ProveTransactionProgramFailed(ExecutionError),
     InvalidAccountDelta(AccountError),
     InvalidTransactionOutput(TransactionOutputError),
     ProvenTransactionError(ProvenTransactionError),
+    RemoteProverConnectionFailed(String),
+    RemoteProvingFailed(String),
 }
 
 impl Display for TransactionProverError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionProverError::CompileTransactionFailed(inner) => {
+                write!(f, "Compiling transaction program failed: {}", inner)
+            },
             TransactionProverError::ProveTransactionProgramFailed(inner) => {
                 write!(f, "Proving transaction failed: {}", inner)
             },
@@ -92,6 +131,12 @@ impl Display for TransactionProverError {
             TransactionProverError::ProvenTransactionError(inner) => {
                 write!(f, "Building proven transaction error: {}", inner)
             },
+            TransactionProverError::RemoteProverConnectionFailed(inner) => {
+                write!(f, "Connection to remote prover failed: {}", inner)
+            },
+            TransactionProverError::RemoteProvingFailed(inner) => {
+                write!(f, "Remote proving failed: {}", inner)
+            },
         }
     }
 }
@@ -138,3 +183,22 @@ impl fmt::Display for DataStoreError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for DataStoreError {}
+
+// AUTHENTICATION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthenticationError {
+    InternalError(String),
+    RejectedSignature(String),
+    UnknownKey(Digest),
+}
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthenticationError {}
@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::KernelAssertionError;
+use miden_objects::{
+    accounts::AccountId,
+    notes::NoteId,
+    transaction::{InputNote, InputNotes, TransactionArgs, TransactionInputs},
+};
+
+use super::{simulation::FalconAuthStub, DataStore, TransactionExecutor, TransactionExecutorError};
+use crate::DataStoreError;
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Checks which notes can be consumed by an account.
+///
+/// Each note is checked in isolation, i.e., by simulating a transaction which consumes only this
+/// note (and executes the provided transaction script, if any) against the account. Thus, the
+/// checker determines whether the note could be consumed right now, but not whether a set of
+/// notes could be consumed together in a single transaction.
+///
+/// A note is not consumable by the account if:
+/// - The note script calls procedures which are not a part of the account interface.
+/// - The execution of the note script fails, e.g., because a P2ID note targets another account,
+///   or because the reclaim height of a P2IDR note has not been reached yet.
+pub struct NoteConsumptionChecker<'a, D> {
+    executor: &'a TransactionExecutor<D>,
+}
+
+impl<'a, D: DataStore> NoteConsumptionChecker<'a, D> {
+    /// Returns a new [NoteConsumptionChecker] which uses the provided executor to check notes.
+    ///
+    /// The interface of the account against which the notes are checked is expected to be loaded
+    /// into the executor.
+    pub fn new(executor: &'a TransactionExecutor<D>) -> Self {
+        Self { executor }
+    }
+
+    /// Checks whether each of the provided notes can be consumed by the specified account, and
+    /// returns the consumption status of each of the notes.
+    ///
+    /// The account, the block header and the chain MMR required to execute the transactions are
+    /// fetched from the [DataStore] for all notes at once. If this fails, the data is fetched for
+    /// each note separately, so that the failure is reported only for the notes which caused it
+    /// (see [NoteConsumptionStatus::FetchInputsFailed]).
+    ///
+    /// The provided transaction arguments are used for each of the simulated transactions. In
+    /// most cases, they should contain a transaction script which authenticates the transaction
+    /// (see [TransactionExecutor::simulate_transaction()] for details on how signatures are
+    /// handled).
+    pub fn check_notes_consumability(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[InputNote],
+        tx_args: TransactionArgs,
+    ) -> Vec<(NoteId, NoteConsumptionStatus)> {
+        let data_store = &self.executor.data_store;
+        let falcon_auth_stub = FalconAuthStub::new();
+
+        let note_ids = notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+        let shared_inputs =
+            data_store.get_transaction_inputs(account_id, block_ref, &note_ids).ok();
+
+        notes
+            .iter()
+            .map(|note| {
+                let fetched_inputs = match &shared_inputs {
+                    Some(tx_inputs) => single_note_inputs(tx_inputs, note),
+                    None => data_store
+                        .get_transaction_inputs(account_id, block_ref, &[note.id()])
+                        .and_then(|tx_inputs| single_note_inputs(&tx_inputs, note)),
+                };
+
+                let status = match fetched_inputs {
+                    Ok(tx_inputs) => {
+                        match self.check_note(
+                            account_id,
+                            tx_inputs,
+                            tx_args.clone(),
+                            &falcon_auth_stub,
+                        ) {
+                            Ok(()) => NoteConsumptionStatus::Consumable,
+                            Err(err) => NoteConsumptionStatus::Unconsumable(err),
+                        }
+                    },
+                    Err(err) => NoteConsumptionStatus::FetchInputsFailed(err),
+                };
+                (note.id(), status)
+            })
+            .collect()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the transaction defined by the provided inputs, thereby verifying that the note
+    /// is compatible with the account interface, and simulates its execution.
+    fn check_note(
+        &self,
+        account_id: AccountId,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+        falcon_auth_stub: &FalconAuthStub,
+    ) -> Result<(), TransactionExecutorError> {
+        let (tx_inputs, authenticator) = falcon_auth_stub.apply(tx_inputs);
+        let transaction = self.executor.compile_transaction(account_id, tx_inputs, tx_args)?;
+        self.executor.simulate_prepared_transaction(transaction, authenticator)?;
+        Ok(())
+    }
+}
+
+/// Returns the transaction inputs consisting of the account, the block header and the chain MMR
+/// of the provided inputs, and of the provided note as the only input note.
+///
+/// # Errors
+/// Returns an error if the chain MMR of the provided inputs does not authenticate the note.
+fn single_note_inputs(
+    tx_inputs: &TransactionInputs,
+    note: &InputNote,
+) -> Result<TransactionInputs, DataStoreError> {
+    TransactionInputs::new(
+        tx_inputs.account().clone(),
+        tx_inputs.account_seed(),
+        *tx_inputs.block_header(),
+        tx_inputs.block_chain().clone(),
+        InputNotes::new(vec![note.clone()]).expect("a single note is a valid set of input notes"),
+    )
+    .map_err(DataStoreError::InvalidTransactionInput)
+}
+
+// NOTE CONSUMPTION STATUS
+// ================================================================================================
+
+/// Describes whether a note can be consumed by an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteConsumptionStatus {
+    /// The note can be consumed by the account.
+    Consumable,
+    /// The note can not be consumed by the account for the specified reason.
+    Unconsumable(TransactionExecutorError),
+    /// The note could not be checked because the data required to consume it could not be
+    /// fetched from the [DataStore].
+    FetchInputsFailed(DataStoreError),
+}
+
+impl NoteConsumptionStatus {
+    /// Returns true if the note can be consumed by the account.
+    pub fn is_consumable(&self) -> bool {
+        matches!(self, Self::Consumable)
+    }
+
+    /// Returns the kernel assertion which failed while consuming the note, if the note can not
+    /// be consumed because of a failed assertion in the transaction kernel, the Miden library, or
+    /// a standard note script.
+    pub fn kernel_error(&self) -> Option<KernelAssertionError> {
+        match self {
+            Self::Unconsumable(TransactionExecutorError::KernelAssertionFailed {
+                error, ..
+            }) => Some(*error),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "async")]
+use core::future::Future;
+
 use miden_objects::{
     accounts::AccountId, assembly::ModuleAst, notes::NoteId, transaction::TransactionInputs,
 };
@@ -35,3 +38,31 @@ pub trait DataStore {
     /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
 }
+
+// ASYNC DATA STORE TRAIT
+// ================================================================================================
+
+/// The [AsyncDataStore] trait is the asynchronous counterpart of the [DataStore] trait.
+///
+/// It allows data required for transaction execution to be fetched from sources which are
+/// accessed asynchronously (e.g., a remote node), without blocking the executor. The returned
+/// futures are required to be [Send] so that they can be driven by multi-threaded executors.
+#[cfg(feature = "async")]
+pub trait AsyncDataStore {
+    /// Returns account, chain, and input note data required to execute a transaction against
+    /// the account with the specified ID and consuming the set of specified input notes.
+    ///
+    /// See [DataStore::get_transaction_inputs()] for details.
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+    ) -> impl Future<Output = Result<TransactionInputs, DataStoreError>> + Send;
+
+    /// Returns the account code [ModuleAst] associated with the the specified [AccountId].
+    fn get_account_code(
+        &self,
+        account_id: AccountId,
+    ) -> impl Future<Output = Result<ModuleAst, DataStoreError>> + Send;
+}
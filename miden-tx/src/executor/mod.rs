@@ -1,23 +1,33 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
-    assembly::ProgramAst,
-    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
+    assembly::{ModuleAst, ProgramAst},
+    transaction::{OutputNotes, TransactionArgs, TransactionInputs, TransactionScript},
     vm::{Program, StackOutputs},
     Felt, Word, ZERO,
 };
-use vm_processor::ExecutionOptions;
+use vm_processor::{AdviceProvider, ExecutionError, ExecutionOptions, MemAdviceProvider};
 
 use super::{
+    auth::TransactionAuthenticator,
+    host::{TransactionMeasurements, TransactionProgress},
     AccountCode, AccountId, Digest, ExecutedTransaction, NoteId, NoteScript, PreparedTransaction,
     RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
     TransactionHost,
 };
 
 mod data;
+#[cfg(feature = "async")]
+pub use data::AsyncDataStore;
 pub use data::DataStore;
 
+mod consumption;
+pub use consumption::{NoteConsumptionChecker, NoteConsumptionStatus};
+
+mod simulation;
+pub use simulation::SimulatedTransaction;
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -29,54 +39,61 @@ pub use data::DataStore;
 /// - Execute the transaction program and create an [ExecutedTransaction].
 ///
 /// The transaction executor is generic over the [DataStore] which allows it to be used with
-/// different data backend implementations.
+/// different data backend implementations. When the `async` feature is enabled, the executor can
+/// also be used with an `AsyncDataStore` via [TransactionExecutor::execute_transaction_async()].
 ///
 /// The [TransactionExecutor::execute_transaction()] method is the main entry point for the
 /// executor and produces an [ExecutedTransaction] for the transaction. The executed transaction
 /// can then be used to by the prover to generate a proof transaction execution.
-pub struct TransactionExecutor<D: DataStore> {
+///
+/// Optionally, the executor can be equipped with a [TransactionAuthenticator] which is used to
+/// sign the messages requested by the transaction kernel during execution (see
+/// [TransactionExecutor::with_authenticator()]).
+///
+/// To preview the effects of a transaction before it is signed, the transaction can be simulated
+/// via [TransactionExecutor::simulate_transaction()].
+///
+/// To find out how many cycles the individual phases of a transaction take, the transaction can be
+/// executed via [TransactionExecutor::execute_transaction_with_measurements()], which enables
+/// tracing of the transaction kernel and returns [TransactionMeasurements] alongside the
+/// [ExecutedTransaction].
+pub struct TransactionExecutor<D> {
     data_store: D,
+    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
     compiler: TransactionCompiler,
     exec_options: ExecutionOptions,
 }
 
-impl<D: DataStore> TransactionExecutor<D> {
+impl<D> TransactionExecutor<D> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new [TransactionExecutor] instance with the specified [DataStore].
+    /// Creates a new [TransactionExecutor] instance with the specified data store.
+    ///
+    /// The data store is expected to implement either the [DataStore] trait or, when the `async`
+    /// feature is enabled, the `AsyncDataStore` trait.
     pub fn new(data_store: D) -> Self {
         Self {
             data_store,
+            authenticator: None,
             compiler: TransactionCompiler::new(),
             exec_options: ExecutionOptions::default(),
         }
     }
 
-    // STATE MUTATORS
-    // --------------------------------------------------------------------------------------------
-
-    /// Fetches the account code from the [DataStore], compiles it, and loads the compiled code
-    /// into the internal cache.
+    /// Sets the [TransactionAuthenticator] which is used to sign messages requested by the
+    /// transaction kernel during execution, and returns the resulting executor.
     ///
-    /// This also returns the [AccountCode] object built from the loaded account code.
-    ///
-    /// # Errors:
-    /// Returns an error if:
-    /// - If the account code cannot be fetched from the [DataStore].
-    /// - If the account code fails to be loaded into the compiler.
-    pub fn load_account(
-        &mut self,
-        account_id: AccountId,
-    ) -> Result<AccountCode, TransactionExecutorError> {
-        let account_code = self
-            .data_store
-            .get_account_code(account_id)
-            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
-        self.compiler
-            .load_account(account_id, account_code)
-            .map_err(TransactionExecutorError::LoadAccountFailed)
+    /// The signatures generated by the authenticator are included into the advice witness of the
+    /// executed transaction, and thus the transaction can be proven without access to the secret
+    /// keys.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn TransactionAuthenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
     }
 
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
     /// Loads the provided account interface (vector of procedure digests) into the compiler.
     ///
     /// Returns the old interface for the specified account ID if it previously existed.
@@ -121,6 +138,180 @@ impl<D: DataStore> TransactionExecutor<D> {
             .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
     }
 
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the provided account code into the compiler and returns the [AccountCode] object
+    /// built from it.
+    fn load_account_code(
+        &mut self,
+        account_id: AccountId,
+        account_code: ModuleAst,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        self.compiler
+            .load_account(account_id, account_code)
+            .map_err(TransactionExecutorError::LoadAccountFailed)
+    }
+
+    /// Compiles the transaction defined by the provided inputs and arguments into an executable
+    /// program using the [TransactionCompiler], and returns a [PreparedTransaction].
+    ///
+    /// # Errors:
+    /// Returns an error if the transaction can not be compiled.
+    fn compile_transaction(
+        &self,
+        account_id: AccountId,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        let tx_program = self
+            .compiler
+            .compile_transaction(
+                account_id,
+                tx_inputs.input_notes(),
+                tx_args.tx_script().map(|x| x.code()),
+            )
+            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+
+        Ok(PreparedTransaction::new(tx_program, tx_inputs, tx_args))
+    }
+
+    /// Executes the provided [PreparedTransaction] using the specified execution options and
+    /// returns an [ExecutedTransaction] together with the [TransactionMeasurements] of its
+    /// execution.
+    ///
+    /// The cycle counts of the transaction kernel phases are measured only if tracing is enabled
+    /// in the execution options.
+    ///
+    /// # Errors:
+    /// Returns an error if the transaction program can not be executed. If tracing is enabled in
+    /// the execution options, the error is returned as
+    /// [TransactionExecutorError::MeasuredExecutionFailed] carrying the measurements and the
+    /// progress recorded up to the failure.
+    fn execute_prepared_transaction(
+        &self,
+        transaction: PreparedTransaction,
+        exec_options: ExecutionOptions,
+    ) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let advice_recorder: RecAdviceProvider = advice_inputs.into();
+        let mut host = TransactionHost::new(
+            transaction.account().into(),
+            advice_recorder,
+            self.authenticator.clone(),
+        );
+
+        let measure = |host: &TransactionHost<RecAdviceProvider>, total_cycles: usize| {
+            TransactionMeasurements::new(
+                host.tx_progress(),
+                transaction.input_notes().iter().map(|note| note.id()),
+                total_cycles,
+            )
+        };
+
+        let result = match vm_processor::execute(
+            transaction.program(),
+            stack_inputs,
+            &mut host,
+            exec_options,
+        ) {
+            Ok(result) => result,
+            Err(err) => {
+                // the VM does not report the number of executed cycles on failure, and thus
+                // the clock cycle of a failed assertion is the best available estimate
+                let total_cycles = match err {
+                    ExecutionError::FailedAssertion { clk, .. } => clk as usize,
+                    _ => 0,
+                };
+                let measurements = measure(&host, total_cycles);
+                let progress = host.tx_progress().clone();
+                let error = map_execution_error(err, &host);
+                return Err(wrap_measured_error(error, exec_options, measurements, progress));
+            },
+        };
+
+        let measurements = measure(&host, result.trace_len_summary().main_trace_len());
+        let progress = host.tx_progress().clone();
+
+        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+
+        match build_executed_transaction(
+            tx_program,
+            tx_args,
+            tx_inputs,
+            result.stack_outputs().clone(),
+            host,
+        ) {
+            Ok(executed_transaction) => Ok((executed_transaction, measurements)),
+            Err(error) => Err(wrap_measured_error(error, exec_options, measurements, progress)),
+        }
+    }
+
+    /// Executes the provided [PreparedTransaction] without recording the advice witness and
+    /// returns a [SimulatedTransaction].
+    ///
+    /// The signatures requested by the transaction kernel are provided by the specified
+    /// authenticator. If no authenticator is specified, the authenticator of this executor (if
+    /// any) is used instead.
+    ///
+    /// # Errors:
+    /// Returns an error if the transaction program can not be executed.
+    fn simulate_prepared_transaction(
+        &self,
+        transaction: PreparedTransaction,
+        authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+        let authenticator = authenticator.or_else(|| self.authenticator.clone());
+        let mut host =
+            TransactionHost::new(transaction.account().into(), advice_provider, authenticator);
+
+        let result = vm_processor::execute(
+            transaction.program(),
+            stack_inputs,
+            &mut host,
+            self.exec_options,
+        )
+        .map_err(|err| map_execution_error(err, &host))?;
+
+        let (_, account_delta, output_notes, _) = host.into_parts();
+        let output_notes = OutputNotes::new(output_notes)
+            .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+
+        Ok(SimulatedTransaction::new(
+            transaction.account().id(),
+            account_delta,
+            output_notes,
+            result.trace_len_summary().main_trace_len(),
+        ))
+    }
+}
+
+impl<D: DataStore> TransactionExecutor<D> {
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the account code from the [DataStore], compiles it, and loads the compiled code
+    /// into the internal cache.
+    ///
+    /// This also returns the [AccountCode] object built from the loaded account code.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account code cannot be fetched from the [DataStore].
+    /// - If the account code fails to be loaded into the compiler.
+    pub fn load_account(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .data_store
+            .get_account_code(account_id)
+            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
+        self.load_account_code(account_id, account_code)
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -144,28 +335,77 @@ impl<D: DataStore> TransactionExecutor<D> {
         tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let transaction = self.prepare_transaction(account_id, block_ref, notes, tx_args)?;
+        let (executed_transaction, _) =
+            self.execute_prepared_transaction(transaction, self.exec_options)?;
+        Ok(executed_transaction)
+    }
 
-        let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
-        let advice_recorder: RecAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(transaction.account().into(), advice_recorder);
-
-        let result = vm_processor::execute(
-            transaction.program(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-        )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+    /// Prepares and executes a transaction specified by the provided arguments with tracing
+    /// enabled, and returns an [ExecutedTransaction] together with the [TransactionMeasurements]
+    /// of its execution.
+    ///
+    /// The measurements contain the number of cycles spent in each phase of the transaction kernel
+    /// (prologue, notes processing, execution of each note, transaction script processing, and
+    /// epilogue) as well as the events emitted by the transaction kernel in the order in which
+    /// they were emitted.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed. In this case, the error is returned as
+    ///   [TransactionExecutorError::MeasuredExecutionFailed] which carries the measurements and
+    ///   the progress of the transaction kernel recorded up to the failure.
+    pub fn execute_transaction_with_measurements(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(ExecutedTransaction, TransactionMeasurements), TransactionExecutorError> {
+        let transaction = self.prepare_transaction(account_id, block_ref, notes, tx_args)?;
+        self.execute_prepared_transaction(transaction, self.exec_options.with_tracing())
+    }
 
-        let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
+    /// Simulates a transaction specified by the provided arguments and returns a
+    /// [SimulatedTransaction] describing the changes the transaction would make to the account,
+    /// the notes it would create, and the number of cycles it would take.
+    ///
+    /// The transaction is executed without recording the advice witness, and thus the simulated
+    /// transaction can not be proven. If the account is an existing account which uses the
+    /// `auth_tx_rpo_falcon512` procedure, the account public key is replaced with an ephemeral one
+    /// for the purposes of the simulation, so that the transaction can be simulated without the
+    /// signature of the account owner. In this case, the authenticator of this executor is not
+    /// invoked.
+    ///
+    /// Signatures are verified by the authentication procedures against the keys committed to in
+    /// the account storage, and thus they can not be stubbed out for other accounts: the ID of a
+    /// new account commits to its initial storage, and the keys of other authentication schemes
+    /// (e.g., ECDSA or multisig) are not replaced. For such accounts, the signatures must either
+    /// be provided by the authenticator of this executor, or be present in the advice map of the
+    /// transaction arguments. Accounts which do not require signatures (e.g., accounts using
+    /// `auth_tx_no_auth`) can always be simulated.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    pub fn simulate_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        let tx_inputs = self
+            .data_store
+            .get_transaction_inputs(account_id, block_ref, notes)
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        build_executed_transaction(
-            tx_program,
-            tx_args,
-            tx_inputs,
-            result.stack_outputs().clone(),
-            host,
-        )
+        let (tx_inputs, authenticator) = simulation::FalconAuthStub::new().apply(tx_inputs);
+        let transaction = self.compile_transaction(account_id, tx_inputs, tx_args)?;
+        self.simulate_prepared_transaction(transaction, authenticator)
     }
 
     // HELPER METHODS
@@ -191,22 +431,110 @@ impl<D: DataStore> TransactionExecutor<D> {
             .get_transaction_inputs(account_id, block_ref, notes)
             .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        let tx_program = self
-            .compiler
-            .compile_transaction(
-                account_id,
-                tx_inputs.input_notes(),
-                tx_args.tx_script().map(|x| x.code()),
-            )
-            .map_err(TransactionExecutorError::CompileTransactionFailed)?;
+        self.compile_transaction(account_id, tx_inputs, tx_args)
+    }
+}
 
-        Ok(PreparedTransaction::new(tx_program, tx_inputs, tx_args))
+#[cfg(feature = "async")]
+impl<D: AsyncDataStore> TransactionExecutor<D> {
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the account code from the [AsyncDataStore], compiles it, and loads the compiled
+    /// code into the internal cache.
+    ///
+    /// This is the asynchronous counterpart of [TransactionExecutor::load_account()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account code cannot be fetched from the [AsyncDataStore].
+    /// - If the account code fails to be loaded into the compiler.
+    pub async fn load_account_async(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountCode, TransactionExecutorError> {
+        let account_code = self
+            .data_store
+            .get_account_code(account_id)
+            .await
+            .map_err(TransactionExecutorError::FetchAccountCodeFailed)?;
+        self.load_account_code(account_id, account_code)
+    }
+
+    // TRANSACTION EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns an
+    /// [ExecutedTransaction].
+    ///
+    /// This is the asynchronous counterpart of [TransactionExecutor::execute_transaction()]. Only
+    /// fetching the data from the [AsyncDataStore] is asynchronous; compilation and execution of
+    /// the transaction program are performed synchronously.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [AsyncDataStore].
+    /// - If the transaction program can not be compiled.
+    /// - If the transaction program can not be executed.
+    pub async fn execute_transaction_async(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = self
+            .data_store
+            .get_transaction_inputs(account_id, block_ref, notes)
+            .await
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let transaction = self.compile_transaction(account_id, tx_inputs, tx_args)?;
+        let (executed_transaction, _) =
+            self.execute_prepared_transaction(transaction, self.exec_options)?;
+        Ok(executed_transaction)
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Converts an error returned by the VM during the execution of a transaction into a
+/// [TransactionExecutorError].
+///
+/// If the execution failed because the authenticator failed to provide a signature, the error
+/// returned by the authenticator is reported instead of the error returned by the VM.
+fn map_execution_error<A: AdviceProvider>(
+    error: ExecutionError,
+    host: &TransactionHost<A>,
+) -> TransactionExecutorError {
+    match (&error, host.authentication_error()) {
+        (ExecutionError::FailedSignatureGeneration(_), Some(auth_error)) => {
+            TransactionExecutorError::SignatureGenerationFailed(auth_error.clone())
+        },
+        _ => error.into(),
+    }
+}
+
+/// Wraps the provided error into [TransactionExecutorError::MeasuredExecutionFailed] if tracing is
+/// enabled in the execution options, and returns it unchanged otherwise.
+fn wrap_measured_error(
+    error: TransactionExecutorError,
+    exec_options: ExecutionOptions,
+    measurements: TransactionMeasurements,
+    progress: TransactionProgress,
+) -> TransactionExecutorError {
+    if exec_options.enable_tracing() {
+        TransactionExecutorError::MeasuredExecutionFailed {
+            error: Box::new(error),
+            measurements: Box::new(measurements),
+            progress: Box::new(progress),
+        }
+    } else {
+        error
+    }
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction(
     program: Program,
@@ -215,9 +543,13 @@ fn build_executed_transaction(
     stack_outputs: StackOutputs,
     host: TransactionHost<RecAdviceProvider>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-    let (advice_recorder, account_delta, output_notes) = host.into_parts();
+    let (advice_recorder, account_delta, output_notes, generated_signatures) = host.into_parts();
+
+    let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
 
-    let (advice_witness, _, map, _store) = advice_recorder.finalize();
+    // include the signatures generated during execution into the witness so that the transaction
+    // can be proven without invoking the authenticator again
+    advice_witness.extend_map(generated_signatures);
 
     let tx_outputs =
         TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
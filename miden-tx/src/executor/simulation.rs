@@ -0,0 +1,159 @@
+use alloc::sync::Arc;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{
+    accounts::{Account, AccountCode, AccountDelta, AccountId},
+    assembly::ModuleAst,
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+    transaction::{OutputNotes, TransactionInputs},
+    Digest, Word,
+};
+
+use crate::auth::{BasicAuthenticator, TransactionAuthenticator};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the storage slot in which the `auth_tx_rpo_falcon512` procedure expects the public key
+/// of the account (see `miden::contracts::auth::basic`).
+const FALCON_PUB_KEY_SLOT: u8 = 0;
+
+// SIMULATED TRANSACTION
+// ================================================================================================
+
+/// Describes the outcome of a transaction simulated via
+/// [TransactionExecutor::simulate_transaction()](super::TransactionExecutor::simulate_transaction).
+///
+/// Unlike an [ExecutedTransaction](miden_objects::transaction::ExecutedTransaction), a simulated
+/// transaction does not contain the advice witness and thus can not be proven.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedTransaction {
+    account_id: AccountId,
+    account_delta: AccountDelta,
+    output_notes: OutputNotes,
+    cycle_count: usize,
+}
+
+impl SimulatedTransaction {
+    /// Returns a new [SimulatedTransaction] instantiated from the provided data.
+    pub fn new(
+        account_id: AccountId,
+        account_delta: AccountDelta,
+        output_notes: OutputNotes,
+        cycle_count: usize,
+    ) -> Self {
+        Self {
+            account_id,
+            account_delta,
+            output_notes,
+            cycle_count,
+        }
+    }
+
+    /// Returns the ID of the account against which the transaction was simulated.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the changes the transaction would make to the account.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes the transaction would create.
+    pub fn output_notes(&self) -> &OutputNotes {
+        &self.output_notes
+    }
+
+    /// Returns the number of cycles the transaction program took to execute.
+    pub fn cycle_count(&self) -> usize {
+        self.cycle_count
+    }
+}
+
+// FALCON AUTHENTICATION STUB
+// ================================================================================================
+
+/// Replaces the public key used by the `auth_tx_rpo_falcon512` procedure of accounts with an
+/// ephemeral one, and provides an authenticator which holds the corresponding secret key.
+///
+/// This allows transactions to be simulated without the signature of the account owner, while
+/// still executing the signature verification so that the number of cycles is not affected.
+///
+/// Generating the ephemeral key and compiling the `auth_tx_rpo_falcon512` procedure are not free,
+/// and thus a single stub should be used for all transactions simulated together.
+pub(super) struct FalconAuthStub {
+    procedure_root: Digest,
+    public_key: Word,
+    authenticator: Arc<dyn TransactionAuthenticator>,
+}
+
+impl FalconAuthStub {
+    /// Returns a new [FalconAuthStub] with a freshly generated ephemeral key.
+    pub fn new() -> Self {
+        let mut rng = RpoRandomCoin::new(Word::default());
+        let key = SecretKey::with_rng(&mut rng);
+        let public_key = key.public_key().into();
+        let authenticator = BasicAuthenticator::new(&[key], rng);
+
+        Self {
+            procedure_root: falcon_auth_procedure_root(),
+            public_key,
+            authenticator: Arc::new(authenticator),
+        }
+    }
+
+    /// Replaces the public key of the account in the provided transaction inputs with the
+    /// ephemeral one, and returns the updated transaction inputs together with the authenticator
+    /// which holds the corresponding secret key.
+    ///
+    /// The inputs are returned unchanged (and no authenticator is returned) if the account does
+    /// not use the `auth_tx_rpo_falcon512` procedure, or if the account is new. In the latter
+    /// case, the ID of the account commits to its initial storage, and thus the storage can not be
+    /// modified. Accounts using the `auth_tx_rpo_falcon512_multisig` procedure are not affected
+    /// either, since their keys are stored in a storage map rather than in the public key slot.
+    pub fn apply(
+        &self,
+        tx_inputs: TransactionInputs,
+    ) -> (TransactionInputs, Option<Arc<dyn TransactionAuthenticator>>) {
+        let account = tx_inputs.account();
+        if account.is_new() || !account.code().has_procedure(self.procedure_root) {
+            return (tx_inputs, None);
+        }
+
+        let mut storage = account.storage().clone();
+        if storage.set_item(FALCON_PUB_KEY_SLOT, self.public_key).is_err() {
+            return (tx_inputs, None);
+        }
+
+        let (account, account_seed, block_header, block_chain, input_notes) =
+            tx_inputs.into_parts();
+        let account = Account::new(
+            account.id(),
+            account.vault().clone(),
+            storage,
+            account.code().clone(),
+            account.nonce(),
+        );
+        let tx_inputs =
+            TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+                .expect("modifying the storage of an existing account keeps the inputs valid");
+
+        (tx_inputs, Some(self.authenticator.clone()))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the MAST root of the `auth_tx_rpo_falcon512` procedure.
+fn falcon_auth_procedure_root() -> Digest {
+    let source = "
+        use.miden::contracts::auth::basic
+        export.basic::auth_tx_rpo_falcon512
+    ";
+    let module = ModuleAst::parse(source).expect("auth module is well formed");
+    let code = AccountCode::new(module, &TransactionKernel::assembler())
+        .expect("auth module can be compiled");
+    code.procedures()[0]
+}
@@ -1,8 +1,11 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_objects::{
-    accounts::{AccountDelta, AccountId, AccountStorageDelta, AccountStub, AccountVaultDelta},
-    assets::{Asset, FungibleAsset, NonFungibleAsset},
+    accounts::{
+        AccountDelta, AccountId, AccountStorageDelta, AccountStub, AccountVaultDelta,
+        StorageArrayDelta, StorageMapDelta,
+    },
+    assets::{Asset, NonFungibleAsset},
     Digest, Felt, Word, EMPTY_WORD, ZERO,
 };
 
@@ -12,7 +15,7 @@ use miden_objects::{
 /// Keeps track of changes made to the account during transaction execution.
 ///
 /// Currently, this tracks:
-/// - Changes to the account storage slots.
+/// - Changes to the account storage slots, storage maps and storage arrays.
 /// - Changes to the account vault.
 /// - Changes to the account nonce.
 ///
@@ -75,9 +78,15 @@ impl AccountDeltaTracker {
 ///
 /// The delta tracker is composed of:
 /// - A map which records the latest states for the updated storage slots.
+/// - A map which records the latest states for the updated storage map items, grouped by the
+///   index of the slot containing the storage map.
+/// - A map which records the latest states for the updated storage array elements, grouped by the
+///   index of the slot containing the storage array.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorageDeltaTracker {
     slot_updates: BTreeMap<u8, Word>,
+    maps_updates: BTreeMap<u8, BTreeMap<Digest, Word>>,
+    arrays_updates: BTreeMap<u8, BTreeMap<u64, Word>>,
 }
 
 impl AccountStorageDeltaTracker {
@@ -95,13 +104,54 @@ impl AccountStorageDeltaTracker {
             }
         }
 
-        AccountStorageDelta { cleared_items, updated_items }
+        let mut updated_maps = Vec::new();
+        for (idx, map_updates) in self.maps_updates {
+            let mut map_delta = StorageMapDelta::default();
+            for (key, value) in map_updates {
+                if value == EMPTY_WORD {
+                    map_delta.cleared_leaves.push(key.into());
+                } else {
+                    map_delta.updated_leaves.push((key.into(), value));
+                }
+            }
+            updated_maps.push((idx, map_delta));
+        }
+
+        let mut updated_arrays = Vec::new();
+        for (idx, array_updates) in self.arrays_updates {
+            let mut array_delta = StorageArrayDelta::default();
+            for (position, value) in array_updates {
+                if value == EMPTY_WORD {
+                    array_delta.cleared_elements.push(position);
+                } else {
+                    array_delta.updated_elements.push((position, value));
+                }
+            }
+            updated_arrays.push((idx, array_delta));
+        }
+
+        AccountStorageDelta {
+            cleared_items,
+            updated_items,
+            updated_maps,
+            updated_arrays,
+        }
     }
 
     /// Tracks a slot change
     pub fn slot_update(&mut self, slot_index: u8, new_slot_value: [Felt; 4]) {
         self.slot_updates.insert(slot_index, new_slot_value);
     }
+
+    /// Tracks a change of an item in the storage map located in the specified slot
+    pub fn map_update(&mut self, slot_index: u8, key: Digest, new_value: Word) {
+        self.maps_updates.entry(slot_index).or_default().insert(key, new_value);
+    }
+
+    /// Tracks a change of an element in the storage array located in the specified slot
+    pub fn array_update(&mut self, slot_index: u8, position: u64, new_value: Word) {
+        self.arrays_updates.entry(slot_index).or_default().insert(position, new_value);
+    }
 }
 
 // ACCOUNT VAULT DELTA TRACKER
@@ -164,48 +214,33 @@ impl AccountVaultDeltaTracker {
     /// Consumes `self` and returns the [AccountVaultDelta] that represents the changes to the
     /// account's vault.
     pub fn into_delta(self) -> AccountVaultDelta {
-        let mut added_assets = Vec::new();
-        let mut removed_assets = Vec::new();
-
-        // process fungible assets
-        for (faucet_id, amount) in self.fungible_assets {
-            if amount > 0 {
-                added_assets.push(Asset::Fungible(
-                    FungibleAsset::new(
-                        AccountId::new_unchecked(faucet_id.into()),
-                        amount.unsigned_abs() as u64,
-                    )
-                    .expect("fungible asset is well formed"),
-                ));
-            } else {
-                removed_assets.push(Asset::Fungible(
-                    FungibleAsset::new(
-                        AccountId::new_unchecked(faucet_id.into()),
-                        amount.unsigned_abs() as u64,
-                    )
-                    .expect("fungible asset is well formed"),
-                ));
-            }
-        }
+        // process fungible assets; the net amount of a fungible asset is bounded by the maximum
+        // amount of a fungible asset as it is the difference between two valid vault balances
+        let fungible_assets = self
+            .fungible_assets
+            .into_iter()
+            .map(|(faucet_id, amount)| {
+                (faucet_id, i64::try_from(amount).expect("fungible asset delta is well formed"))
+            })
+            .collect();
 
         // process non-fungible assets
+        let mut added_non_fungible_assets = Vec::new();
+        let mut removed_non_fungible_assets = Vec::new();
         for (non_fungible_asset, amount) in self.non_fungible_assets {
+            let asset = unsafe { NonFungibleAsset::new_unchecked(*non_fungible_asset) };
             match amount {
-                1 => {
-                    added_assets.push(Asset::NonFungible(unsafe {
-                        NonFungibleAsset::new_unchecked(*non_fungible_asset)
-                    }));
-                },
-                -1 => {
-                    removed_assets.push(Asset::NonFungible(unsafe {
-                        NonFungibleAsset::new_unchecked(*non_fungible_asset)
-                    }));
-                },
+                1 => added_non_fungible_assets.push(asset),
+                -1 => removed_non_fungible_assets.push(asset),
                 _ => unreachable!("non-fungible asset amount must be 1 or -1"),
             }
         }
 
-        AccountVaultDelta { added_assets, removed_assets }
+        AccountVaultDelta {
+            fungible_assets,
+            added_non_fungible_assets,
+            removed_non_fungible_assets,
+        }
     }
 }
 
@@ -1,29 +1,35 @@
-use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
 
 use miden_lib::transaction::{
-    memory::ACCT_STORAGE_ROOT_PTR, TransactionEvent, TransactionKernelError,
+    memory::{ACCT_STORAGE_ROOT_PTR, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE},
+    TransactionEvent, TransactionKernelError, TransactionTrace,
 };
 use miden_objects::{
     accounts::{AccountDelta, AccountId, AccountStorage, AccountStub},
     assets::Asset,
-    notes::{
-        Note, NoteAssets, NoteEnvelope, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
-        NoteScript, NoteTag, NoteType,
-    },
+    notes::{NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType},
     transaction::OutputNote,
-    Digest,
+    Digest, Hasher,
 };
 use vm_processor::{
     crypto::NodeIndex, AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId,
     ExecutionError, Felt, Host, HostResponse, ProcessState,
 };
 
+use crate::{auth::TransactionAuthenticator, AuthenticationError};
+
 mod account_delta_tracker;
 use account_delta_tracker::AccountDeltaTracker;
 
 mod account_procs;
 use account_procs::AccountProcedureIndexMap;
 
+mod note_builder;
+use note_builder::OutputNoteBuilder;
+
+mod tx_progress;
+pub use tx_progress::{CycleInterval, TransactionMeasurements, TransactionProgress};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -45,24 +51,69 @@ pub struct TransactionHost<A> {
     acct_procedure_index_map: AccountProcedureIndexMap,
 
     /// The list of notes created while executing a transaction.
-    output_notes: Vec<OutputNote>,
+    output_notes: Vec<OutputNoteBuilder>,
+
+    /// Provides signatures requested by the transaction kernel which are not present in the
+    /// advice provider.
+    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+
+    /// Signatures generated by the authenticator while executing a transaction, mapped by
+    /// hash(PUB_KEY, MSG).
+    generated_signatures: BTreeMap<Digest, Vec<Felt>>,
+
+    /// The error returned by the authenticator if it failed to provide a requested signature.
+    authentication_error: Option<AuthenticationError>,
+
+    /// Contains the cycle intervals of the transaction kernel phases (recorded only when tracing
+    /// is enabled) and the events emitted by the transaction kernel.
+    tx_progress: TransactionProgress,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
-    /// Returns a new [TransactionHost] instance with the provided [AdviceProvider].
-    pub fn new(account: AccountStub, adv_provider: A) -> Self {
+    /// Returns a new [TransactionHost] instance with the provided [AdviceProvider] and an
+    /// optional [TransactionAuthenticator].
+    pub fn new(
+        account: AccountStub,
+        adv_provider: A,
+        authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+    ) -> Self {
         let proc_index_map = AccountProcedureIndexMap::new(account.code_root(), &adv_provider);
         Self {
             adv_provider,
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
             output_notes: Vec::new(),
+            authenticator,
+            generated_signatures: BTreeMap::new(),
+            authentication_error: None,
+            tx_progress: TransactionProgress::default(),
         }
     }
 
-    /// Consumes `self` and returns the advice provider and account vault delta.
-    pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>) {
-        (self.adv_provider, self.account_delta.into_delta(), self.output_notes)
+    /// Returns a reference to the progress of the transaction execution.
+    pub fn tx_progress(&self) -> &TransactionProgress {
+        &self.tx_progress
+    }
+
+    /// Returns the error returned by the authenticator if it failed to provide a signature
+    /// requested during transaction execution.
+    ///
+    /// When this happens, the execution fails with [ExecutionError::FailedSignatureGeneration],
+    /// and this error describes the reason for the failure.
+    pub fn authentication_error(&self) -> Option<&AuthenticationError> {
+        self.authentication_error.as_ref()
+    }
+
+    /// Consumes `self` and returns the advice provider, account vault delta, output notes, and
+    /// the signatures generated by the authenticator.
+    pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>, BTreeMap<Digest, Vec<Felt>>) {
+        let output_notes = self.output_notes.into_iter().map(|note| note.build()).collect();
+        (
+            self.adv_provider,
+            self.account_delta.into_delta(),
+            output_notes,
+            self.generated_signatures,
+        )
     }
 
     // EVENT HANDLERS
@@ -85,17 +136,16 @@ impl<A: AdviceProvider> TransactionHost<A> {
             .map_err(|_| TransactionKernelError::MalformedTag(stack[3]))?;
         let asset = Asset::try_from([stack[8], stack[7], stack[6], stack[5]])
             .map_err(TransactionKernelError::MalformedAsset)?;
-        let recipient = Digest::new([stack[12], stack[11], stack[10], stack[9]]);
-        let vault =
-            NoteAssets::new(vec![asset]).map_err(TransactionKernelError::MalformedNoteType)?;
+        let recipient_digest = Digest::new([stack[12], stack[11], stack[10], stack[9]]);
 
         let metadata = NoteMetadata::new(sender, note_type, tag, aux)
             .map_err(TransactionKernelError::MalformedNoteMetadata)?;
 
-        let note = if metadata.note_type() == NoteType::Public {
-            let data = self.adv_provider.get_mapped_values(&recipient).ok_or(
-                TransactionKernelError::MissingNoteDetails(metadata, vault.clone(), recipient),
-            )?;
+        let recipient = if metadata.note_type() == NoteType::Public {
+            let data = self.adv_provider.get_mapped_values(&recipient_digest).ok_or_else(|| {
+                let vault = NoteAssets::new(vec![asset]).expect("single asset is valid");
+                TransactionKernelError::MissingNoteDetails(metadata, vault, recipient_digest)
+            })?;
             if data.len() != 12 {
                 return Err(TransactionKernelError::MalformedRecipientData(data.to_vec()));
             }
@@ -111,20 +161,44 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
             let script = NoteScript::try_from(script_data)
                 .map_err(|_| TransactionKernelError::MalformedNoteScript(script_data.to_vec()))?;
-            let recipient = NoteRecipient::new(serial_num, script, inputs);
-            OutputNote::Public(Note::new(vault, metadata, recipient))
+            Some(NoteRecipient::new(serial_num, script, inputs))
         } else {
-            let note_id = NoteId::new(recipient, vault.commitment());
-            OutputNote::Private(
-                NoteEnvelope::new(note_id, metadata).expect("NoteType checked above"),
-            )
+            None
         };
 
+        let note = OutputNoteBuilder::new(metadata, recipient_digest, recipient, asset)?;
         self.output_notes.push(note);
 
         Ok(())
     }
 
+    /// Extracts the asset being added to a created note from the process state and adds it to
+    /// the note.
+    ///
+    /// Expected stack state: [note_ptr, ASSET, ...]
+    fn on_note_add_asset<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        // the kernel makes sure the note pointer points to an already created note
+        let note_ptr = process.get_stack_item(0).as_int();
+        let note_idx = (note_ptr - CREATED_NOTE_SECTION_OFFSET as u64) / NOTE_MEM_SIZE as u64;
+
+        let asset = Asset::try_from([
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ])
+        .map_err(TransactionKernelError::MalformedAsset)?;
+
+        let note = self
+            .output_notes
+            .get_mut(note_idx as usize)
+            .ok_or(TransactionKernelError::MissingCreatedNote(note_idx))?;
+        note.add_asset(asset)
+    }
+
     fn on_account_push_procedure_index<S: ProcessState>(
         &mut self,
         process: &S,
@@ -193,6 +267,77 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Extracts information from the process state about the storage map item being updated and
+    /// records it in the account delta.
+    ///
+    /// Expected stack state: [slot_index, KEY, NEW_VALUE, ...]
+    pub fn on_account_storage_set_map_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        // get slot index from the stack and make sure it is valid
+        let slot_index = process.get_stack_item(0);
+        if slot_index.as_int() as usize >= AccountStorage::NUM_STORAGE_SLOTS {
+            return Err(TransactionKernelError::InvalidStorageSlotIndex(slot_index.as_int()));
+        }
+
+        // get the key under which the map item is being updated
+        let key = [
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ];
+
+        // get the value to which the map item is being updated
+        let new_value = [
+            process.get_stack_item(8),
+            process.get_stack_item(7),
+            process.get_stack_item(6),
+            process.get_stack_item(5),
+        ];
+
+        let slot_index = slot_index.as_int() as u8;
+        self.account_delta
+            .storage_tracker()
+            .map_update(slot_index, key.into(), new_value);
+
+        Ok(())
+    }
+
+    /// Extracts information from the process state about the storage array element being updated
+    /// and records it in the account delta.
+    ///
+    /// Expected stack state: [slot_index, position, NEW_VALUE, ...]
+    pub fn on_account_storage_set_array_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        // get slot index from the stack and make sure it is valid
+        let slot_index = process.get_stack_item(0);
+        if slot_index.as_int() as usize >= AccountStorage::NUM_STORAGE_SLOTS {
+            return Err(TransactionKernelError::InvalidStorageSlotIndex(slot_index.as_int()));
+        }
+
+        // get the position of the element being updated
+        let position = process.get_stack_item(1).as_int();
+
+        // get the value to which the element is being updated
+        let new_value = [
+            process.get_stack_item(5),
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+        ];
+
+        let slot_index = slot_index.as_int() as u8;
+        self.account_delta
+            .storage_tracker()
+            .array_update(slot_index, position, new_value);
+
+        Ok(())
+    }
+
     // ACCOUNT VAULT UPDATE HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -225,6 +370,55 @@ impl<A: AdviceProvider> TransactionHost<A> {
         self.account_delta.vault_tracker().remove_asset(asset);
         Ok(())
     }
+
+    // ADVICE INJECTOR HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Pushes a signature of the message at the top of the stack onto the advice stack.
+    ///
+    /// The signature is looked up in the advice map under hash(PUB_KEY, MSG). If it is not there
+    /// and the host has an authenticator, the signature is requested from the authenticator and
+    /// recorded so that the transaction can later be proven without access to the secret key.
+    /// Otherwise, the request is handled by the advice provider, which expects the secret key to
+    /// be present in the advice map under PUB_KEY.
+    ///
+    /// Expected stack state: [PUB_KEY, MSG, ...]
+    fn on_signature_requested<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        let pub_key = process.get_stack_word(0);
+        let msg = process.get_stack_word(1);
+        let signature_key = Hasher::merge(&[pub_key.into(), msg.into()]);
+
+        let signature = match self.adv_provider.get_mapped_values(&signature_key) {
+            Some(signature) => signature.to_vec(),
+            None => {
+                let Some(authenticator) = &self.authenticator else {
+                    return self.adv_provider.set_advice(process, &injector);
+                };
+
+                let signature = match authenticator.get_signature(pub_key, msg) {
+                    Ok(signature) => signature,
+                    Err(err) => {
+                        self.authentication_error = Some(err);
+                        return Err(ExecutionError::FailedSignatureGeneration(
+                            "transaction authenticator failed to sign the message",
+                        ));
+                    },
+                };
+                self.generated_signatures.insert(signature_key, signature.clone());
+                signature
+            },
+        };
+
+        for value in signature {
+            self.adv_provider.push_stack(AdviceSource::Value(value))?;
+        }
+
+        Ok(HostResponse::None)
+    }
 }
 
 impl<A: AdviceProvider> Host for TransactionHost<A> {
@@ -241,7 +435,10 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         process: &S,
         injector: AdviceInjector,
     ) -> Result<HostResponse, ExecutionError> {
-        self.adv_provider.set_advice(process, &injector)
+        match injector {
+            AdviceInjector::SigToStack { .. } => self.on_signature_requested(process, injector),
+            injector => self.adv_provider.set_advice(process, &injector),
+        }
     }
 
     fn on_event<S: ProcessState>(
@@ -258,6 +455,8 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             )));
         }
 
+        self.tx_progress.record_event(event.clone());
+
         match event {
             TransactionEvent::AccountVaultAddAsset => self.on_account_vault_add_asset(process),
             TransactionEvent::AccountVaultRemoveAsset => {
@@ -269,9 +468,29 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                 self.on_account_push_procedure_index(process)
             },
             TransactionEvent::NoteCreated => self.on_note_created(process),
+            TransactionEvent::AccountStorageSetMapItem => {
+                self.on_account_storage_set_map_item(process)
+            },
+            TransactionEvent::AccountStorageSetArrayItem => {
+                self.on_account_storage_set_array_item(process)
+            },
+            TransactionEvent::NoteAddAsset => self.on_note_add_asset(process),
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
         Ok(HostResponse::None)
     }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        // traces which are not emitted by the transaction kernel are ignored
+        if let Ok(trace) = TransactionTrace::try_from(trace_id) {
+            self.tx_progress.record_trace(trace, process.clk());
+        }
+
+        Ok(HostResponse::None)
+    }
 }
@@ -0,0 +1,72 @@
+use miden_lib::transaction::TransactionKernelError;
+use miden_objects::{
+    assets::Asset,
+    notes::{Note, NoteAssets, NoteEnvelope, NoteId, NoteMetadata, NoteRecipient},
+    transaction::OutputNote,
+    Digest,
+};
+
+// OUTPUT NOTE BUILDER
+// ================================================================================================
+
+/// Accumulates the data of a note created during transaction execution.
+///
+/// A note is created with a single asset, and more assets can be added to it afterwards. The final
+/// [OutputNote] is built only once all assets have been added to the note, because the ID of a
+/// note commits to its assets.
+pub struct OutputNoteBuilder {
+    metadata: NoteMetadata,
+    recipient_digest: Digest,
+    recipient: Option<NoteRecipient>,
+    assets: NoteAssets,
+}
+
+impl OutputNoteBuilder {
+    /// Returns a new [OutputNoteBuilder] instantiated with the provided metadata, recipient and
+    /// asset.
+    ///
+    /// The full recipient details are expected to be provided only for public notes.
+    pub fn new(
+        metadata: NoteMetadata,
+        recipient_digest: Digest,
+        recipient: Option<NoteRecipient>,
+        asset: Asset,
+    ) -> Result<Self, TransactionKernelError> {
+        let assets =
+            NoteAssets::new(vec![asset]).map_err(TransactionKernelError::MalformedNoteType)?;
+
+        Ok(Self {
+            metadata,
+            recipient_digest,
+            recipient,
+            assets,
+        })
+    }
+
+    /// Adds the provided asset to the note.
+    ///
+    /// # Errors
+    /// Returns an error if the asset could not be added to the assets of the note.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), TransactionKernelError> {
+        self.assets
+            .add_asset(asset)
+            .map_err(TransactionKernelError::FailedToAddAssetToNote)
+    }
+
+    /// Consumes this builder and returns the resulting [OutputNote].
+    ///
+    /// If the full recipient details are available, a public note is returned; otherwise, only
+    /// the note ID and metadata are retained.
+    pub fn build(self) -> OutputNote {
+        match self.recipient {
+            Some(recipient) => OutputNote::Public(Note::new(self.assets, self.metadata, recipient)),
+            None => {
+                let note_id = NoteId::new(self.recipient_digest, self.assets.commitment());
+                OutputNote::Private(
+                    NoteEnvelope::new(note_id, self.metadata)
+                        .expect("note type checked on creation"),
+                )
+            },
+        }
+    }
+}
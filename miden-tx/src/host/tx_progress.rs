@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::{TransactionEvent, TransactionTrace};
+use miden_objects::notes::NoteId;
+
+// TRANSACTION PROGRESS
+// ================================================================================================
+
+/// Keeps track of the progress of a transaction execution.
+///
+/// The progress consists of the clock cycles at which the phases of the transaction kernel have
+/// started and ended, and of the events emitted by the transaction kernel in the order in which
+/// they were emitted. The clock cycles are recorded only if tracing is enabled in the execution
+/// options, since otherwise the transaction kernel traces are not forwarded to the host.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionProgress {
+    prologue: CycleInterval,
+    notes_processing: CycleInterval,
+    note_execution: Vec<CycleInterval>,
+    tx_script_processing: CycleInterval,
+    epilogue: CycleInterval,
+    events: Vec<TransactionEvent>,
+}
+
+impl TransactionProgress {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the cycle interval of the transaction prologue.
+    pub fn prologue(&self) -> &CycleInterval {
+        &self.prologue
+    }
+
+    /// Returns the cycle interval of the notes processing, including the note setup and teardown
+    /// performed by the transaction kernel.
+    pub fn notes_processing(&self) -> &CycleInterval {
+        &self.notes_processing
+    }
+
+    /// Returns the cycle intervals of the individual note executions in the order in which the
+    /// notes were consumed.
+    pub fn note_execution(&self) -> &[CycleInterval] {
+        &self.note_execution
+    }
+
+    /// Returns the cycle interval of the transaction script processing.
+    pub fn tx_script_processing(&self) -> &CycleInterval {
+        &self.tx_script_processing
+    }
+
+    /// Returns the cycle interval of the transaction epilogue.
+    pub fn epilogue(&self) -> &CycleInterval {
+        &self.epilogue
+    }
+
+    /// Returns the events emitted by the transaction kernel in the order in which they were
+    /// emitted.
+    pub fn events(&self) -> &[TransactionEvent] {
+        &self.events
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the clock cycle at which the specified trace was emitted.
+    pub fn record_trace(&mut self, trace: TransactionTrace, clk: u32) {
+        match trace {
+            TransactionTrace::PrologueStart => self.prologue.set_start(clk),
+            TransactionTrace::PrologueEnd => self.prologue.set_end(clk),
+            TransactionTrace::NotesProcessingStart => self.notes_processing.set_start(clk),
+            TransactionTrace::NotesProcessingEnd => self.notes_processing.set_end(clk),
+            TransactionTrace::NoteExecutionStart => {
+                self.note_execution.push(CycleInterval::new(clk))
+            },
+            TransactionTrace::NoteExecutionEnd => {
+                if let Some(interval) = self.note_execution.last_mut() {
+                    interval.set_end(clk)
+                }
+            },
+            TransactionTrace::TxScriptProcessingStart => self.tx_script_processing.set_start(clk),
+            TransactionTrace::TxScriptProcessingEnd => self.tx_script_processing.set_end(clk),
+            TransactionTrace::EpilogueStart => self.epilogue.set_start(clk),
+            TransactionTrace::EpilogueEnd => self.epilogue.set_end(clk),
+        }
+    }
+
+    /// Records the specified event emitted by the transaction kernel.
+    pub fn record_event(&mut self, event: TransactionEvent) {
+        self.events.push(event);
+    }
+}
+
+// CYCLE INTERVAL
+// ================================================================================================
+
+/// An interval of clock cycles between the start and the end of a transaction kernel phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CycleInterval {
+    start: Option<u32>,
+    end: Option<u32>,
+}
+
+impl CycleInterval {
+    /// Returns a new [CycleInterval] which starts at the specified clock cycle.
+    pub fn new(start: u32) -> Self {
+        Self { start: Some(start), end: None }
+    }
+
+    /// Returns the clock cycle at which the interval starts, if it was recorded.
+    pub fn start(&self) -> Option<u32> {
+        self.start
+    }
+
+    /// Returns the clock cycle at which the interval ends, if it was recorded.
+    pub fn end(&self) -> Option<u32> {
+        self.end
+    }
+
+    /// Returns the number of cycles in this interval, or 0 if either of its bounds was not
+    /// recorded.
+    pub fn len(&self) -> usize {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if end >= start => (end - start) as usize,
+            _ => 0,
+        }
+    }
+
+    /// Returns true if the number of cycles in this interval is 0.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn set_start(&mut self, clk: u32) {
+        self.start = Some(clk);
+    }
+
+    fn set_end(&mut self, clk: u32) {
+        self.end = Some(clk);
+    }
+}
+
+// TRANSACTION MEASUREMENTS
+// ================================================================================================
+
+/// Cycle counts and events of a transaction execution.
+///
+/// The cycle counts of the individual transaction kernel phases are non-zero only if the
+/// transaction was executed with tracing enabled (see
+/// [TransactionExecutor::execute_transaction_with_measurements()](crate::TransactionExecutor::execute_transaction_with_measurements)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionMeasurements {
+    /// Number of cycles spent in the transaction prologue.
+    pub prologue: usize,
+    /// Number of cycles spent processing the input notes, including the note setup and teardown.
+    pub notes_processing: usize,
+    /// Number of cycles spent executing each of the input notes, in the order of consumption.
+    pub note_execution: Vec<(NoteId, usize)>,
+    /// Number of cycles spent executing the transaction script.
+    pub tx_script_processing: usize,
+    /// Number of cycles spent in the transaction epilogue.
+    pub epilogue: usize,
+    /// Total number of cycles of the transaction program.
+    pub total_cycles: usize,
+    /// Events emitted by the transaction kernel in the order in which they were emitted.
+    pub events: Vec<TransactionEvent>,
+}
+
+impl TransactionMeasurements {
+    /// Returns new [TransactionMeasurements] built from the provided transaction progress.
+    ///
+    /// The cycle counts of note executions are assigned to the provided note IDs which are
+    /// expected to be in the order in which the notes were consumed.
+    pub fn new(
+        tx_progress: &TransactionProgress,
+        note_ids: impl IntoIterator<Item = NoteId>,
+        total_cycles: usize,
+    ) -> Self {
+        let note_execution = note_ids
+            .into_iter()
+            .zip(tx_progress.note_execution())
+            .map(|(note_id, interval)| (note_id, interval.len()))
+            .collect();
+
+        Self {
+            prologue: tx_progress.prologue().len(),
+            notes_processing: tx_progress.notes_processing().len(),
+            note_execution,
+            tx_script_processing: tx_progress.tx_script_processing().len(),
+            epilogue: tx_progress.epilogue().len(),
+            total_cycles,
+            events: tx_progress.events().to_vec(),
+        }
+    }
+}
@@ -17,25 +17,35 @@ use miden_objects::{
 };
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
+pub mod auth;
+pub use auth::TransactionAuthenticator;
+
 mod compiler;
 pub use compiler::{ScriptTarget, TransactionCompiler};
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor};
+#[cfg(feature = "async")]
+pub use executor::AsyncDataStore;
+pub use executor::{
+    DataStore, NoteConsumptionChecker, NoteConsumptionStatus, SimulatedTransaction,
+    TransactionExecutor,
+};
 
 pub mod host;
-pub use host::TransactionHost;
+pub use host::{TransactionHost, TransactionMeasurements};
 
 mod prover;
-pub use prover::{ProvingOptions, TransactionProver};
+pub use prover::{ProveTransaction, ProvingOptions, TransactionProver};
+#[cfg(feature = "remote-prover")]
+pub use prover::{RemoteTransactionProver, TransactionProverService};
 
 mod verifier;
 pub use verifier::TransactionVerifier;
 
 mod error;
 pub use error::{
-    DataStoreError, TransactionCompilerError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, DataStoreError, TransactionCompilerError, TransactionExecutorError,
+    TransactionProverError, TransactionVerifierError,
 };
 
 #[cfg(test)]
@@ -4,12 +4,41 @@ use miden_objects::{
     transaction::{
         AccountDetails, InputNotes, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness,
     },
+    vm::Program,
 };
 use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
 use vm_processor::MemAdviceProvider;
 
-use super::{TransactionHost, TransactionProverError};
+use super::{TransactionCompiler, TransactionHost, TransactionProverError};
+
+#[cfg(feature = "remote-prover")]
+mod remote;
+#[cfg(feature = "remote-prover")]
+pub use remote::{RemoteTransactionProver, TransactionProverService};
+
+// PROVE TRANSACTION
+// ================================================================================================
+
+/// Defines a component which proves transactions.
+///
+/// The trait is implemented by both the local [TransactionProver] and, when the `remote-prover`
+/// feature is enabled, the `RemoteTransactionProver`, and thus allows the caller to decide where
+/// transactions are proven without changing the code which submits them.
+pub trait ProveTransaction {
+    /// Proves the transaction described by the provided witness and returns a
+    /// [ProvenTransaction].
+    ///
+    /// # Errors
+    /// Returns an error if the transaction could not be proven.
+    fn prove_transaction(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError>;
+}
+
+// TRANSACTION PROVER
+// ================================================================================================
 
 /// Transaction prover is a stateless component which is responsible for proving transactions.
 ///
@@ -32,8 +61,15 @@ impl TransactionProver {
 
     /// Proves the provided transaction and returns a [ProvenTransaction].
     ///
+    /// If the witness retains the executed transaction program (i.e., it was obtained from an
+    /// [ExecutedTransaction](miden_objects::transaction::ExecutedTransaction)), the program is
+    /// proven directly. Otherwise, the program is rebuilt from the account code, the note scripts
+    /// and the transaction script contained in the witness, and so a deserialized witness can be
+    /// proven on a different machine than the one which executed the transaction.
+    ///
     /// # Errors
     /// - If the consumed note data in the transaction witness is corrupt.
+    /// - If the transaction program cannot be compiled.
     /// - If the transaction program cannot be proven.
     /// - If the transaction result is corrupt.
     pub fn prove_transaction<T: Into<TransactionWitness>>(
@@ -42,6 +78,15 @@ impl TransactionProver {
     ) -> Result<ProvenTransaction, TransactionProverError> {
         let tx_witness: TransactionWitness = transaction.into();
 
+        let compiled_program;
+        let program = match tx_witness.program() {
+            Some(program) => program,
+            None => {
+                compiled_program = compile_transaction_program(&tx_witness)?;
+                &compiled_program
+            },
+        };
+
         // extract required data from the transaction witness
         let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
 
@@ -52,13 +97,13 @@ impl TransactionProver {
         let tx_script_root = tx_witness.tx_args().tx_script().map(|script| *script.hash());
 
         let advice_provider: MemAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(tx_witness.account().into(), advice_provider);
+        let mut host = TransactionHost::new(tx_witness.account().into(), advice_provider, None);
         let (stack_outputs, proof) =
-            prove(tx_witness.program(), stack_inputs, &mut host, self.proof_options.clone())
+            prove(program, stack_inputs, &mut host, self.proof_options.clone())
                 .map_err(TransactionProverError::ProveTransactionProgramFailed)?;
 
         // extract transaction outputs and process transaction data
-        let (advice_provider, account_delta, output_notes) = host.into_parts();
+        let (advice_provider, account_delta, output_notes, _) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
         let tx_outputs =
             TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
@@ -69,6 +114,7 @@ impl TransactionProver {
             tx_witness.account().proof_init_hash(),
             tx_outputs.account.hash(),
             block_hash,
+            tx_outputs.expiration_block_num,
             proof,
         )
         .add_input_notes(input_notes)
@@ -100,3 +146,32 @@ impl TransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionError)
     }
 }
+
+impl ProveTransaction for TransactionProver {
+    fn prove_transaction(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        TransactionProver::prove_transaction(self, tx_witness)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Rebuilds the transaction program from the data contained in the provided witness.
+fn compile_transaction_program(
+    tx_witness: &TransactionWitness,
+) -> Result<Program, TransactionProverError> {
+    let mut compiler = TransactionCompiler::new();
+    compiler
+        .load_account(tx_witness.account().id(), tx_witness.account().code().module().clone())
+        .map_err(TransactionProverError::CompileTransactionFailed)?;
+    compiler
+        .compile_transaction(
+            tx_witness.account().id(),
+            tx_witness.input_notes(),
+            tx_witness.tx_args().tx_script().map(|script| script.code()),
+        )
+        .map_err(TransactionProverError::CompileTransactionFailed)
+}
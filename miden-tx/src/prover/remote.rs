@@ -0,0 +1,371 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::{Deserializable, Serializable},
+};
+
+use super::{ProveTransaction, ProvingOptions, TransactionProver, TransactionProverError};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum size (in bytes) of a message exchanged between a remote prover and its clients.
+const MAX_MESSAGE_SIZE: usize = 1 << 26;
+
+/// Default timeout for a prover service reading a request from or writing a response to a single
+/// connection.
+const DEFAULT_IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default timeout for establishing a connection to a remote prover.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default timeout for sending a request to and reading a response from a remote prover. The
+/// response is read only once the transaction is proven, and thus the timeout must accommodate
+/// the proving time.
+const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default maximum number of connections which are handled concurrently by a prover service.
+const DEFAULT_MAX_CONNECTIONS: usize = 4;
+
+const STATUS_OK: u8 = 0;
+const STATUS_ERROR: u8 = 1;
+
+// REMOTE TRANSACTION PROVER
+// ================================================================================================
+
+/// Client for a [TransactionProverService] running on a different machine.
+///
+/// [RemoteTransactionProver] exposes the same `prove_transaction` method as [TransactionProver],
+/// but instead of proving the transaction locally, it sends the serialized [TransactionWitness]
+/// to the remote prover and reads back the serialized [ProvenTransaction].
+///
+/// Each request is sent over a new TCP connection as a length-prefixed message. The response
+/// consists of a status byte followed by a length-prefixed message which contains either the
+/// serialized [ProvenTransaction] or a UTF-8 error description.
+///
+/// Establishing the connection, as well as sending the request and reading the response, are
+/// subject to timeouts, and so an unreachable or stalled prover cannot block the caller
+/// indefinitely.
+pub struct RemoteTransactionProver {
+    address: String,
+    connect_timeout: Duration,
+    io_timeout: Duration,
+}
+
+impl RemoteTransactionProver {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates a new [RemoteTransactionProver] which connects to the prover at the specified
+    /// address.
+    ///
+    /// The prover uses a timeout of 10 seconds for establishing a connection, and a timeout of 5
+    /// minutes for sending a request and reading a response, which includes the proving time.
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            io_timeout: DEFAULT_RESPONSE_TIMEOUT,
+        }
+    }
+
+    /// Sets the timeout for establishing a connection to the remote prover, and returns the
+    /// resulting prover.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        assert!(!connect_timeout.is_zero(), "connect timeout must be greater than zero");
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Sets the timeout for sending a request to and reading a response from the remote prover,
+    /// and returns the resulting prover.
+    ///
+    /// The response is sent by the remote prover only once the transaction is proven, and thus the
+    /// timeout should exceed the expected proving time.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_io_timeout(mut self, io_timeout: Duration) -> Self {
+        assert!(!io_timeout.is_zero(), "I/O timeout must be greater than zero");
+        self.io_timeout = io_timeout;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the address of the remote prover.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    // TRANSACTION PROVER
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the provided transaction on the remote prover and returns a [ProvenTransaction].
+    ///
+    /// # Errors
+    /// - If the connection to the remote prover fails or times out.
+    /// - If the remote prover fails to prove the transaction.
+    /// - If the response of the remote prover is malformed.
+    pub fn prove_transaction<T: Into<TransactionWitness>>(
+        &self,
+        transaction: T,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let tx_witness: TransactionWitness = transaction.into();
+
+        let mut stream = self.connect().map_err(connection_error)?;
+        write_message(&mut stream, &tx_witness.to_bytes()).map_err(connection_error)?;
+
+        let mut status = [0_u8];
+        stream.read_exact(&mut status).map_err(connection_error)?;
+        let response = read_message(&mut stream).map_err(connection_error)?;
+
+        match status[0] {
+            STATUS_OK => ProvenTransaction::read_from_bytes(&response).map_err(|err| {
+                TransactionProverError::RemoteProvingFailed(format!(
+                    "invalid proven transaction: {err}"
+                ))
+            }),
+            STATUS_ERROR => Err(TransactionProverError::RemoteProvingFailed(
+                String::from_utf8_lossy(&response).into_owned(),
+            )),
+            status => Err(TransactionProverError::RemoteProvingFailed(format!(
+                "unknown response status {status}"
+            ))),
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Connects to the remote prover, trying each address the prover address resolves to, and
+    /// sets the I/O timeouts of the resulting stream.
+    fn connect(&self) -> std::io::Result<TcpStream> {
+        let mut last_error = None;
+        for address in self.address.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&address, self.connect_timeout) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(self.io_timeout))?;
+                    stream.set_write_timeout(Some(self.io_timeout))?;
+                    return Ok(stream);
+                },
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("address {} does not resolve to any socket address", self.address),
+            )
+        }))
+    }
+}
+
+impl ProveTransaction for RemoteTransactionProver {
+    fn prove_transaction(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        RemoteTransactionProver::prove_transaction(self, tx_witness)
+    }
+}
+
+// TRANSACTION PROVER SERVICE
+// ================================================================================================
+
+/// A service which proves transactions on behalf of [RemoteTransactionProver]s.
+///
+/// The service reads serialized [TransactionWitness]es from incoming TCP connections, proves them
+/// using a [TransactionProver] with the configured [ProvingOptions], and writes back the
+/// serialized [ProvenTransaction]s.
+///
+/// Connections are handled concurrently by a fixed number of worker threads, and further
+/// connections wait to be accepted until one of the workers becomes available. Reading a request
+/// and writing a response are subject to a timeout, and so a stalled client cannot hold on to a
+/// worker indefinitely.
+pub struct TransactionProverService {
+    prover: TransactionProver,
+    io_timeout: Duration,
+    max_connections: usize,
+}
+
+impl TransactionProverService {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Creates a new [TransactionProverService] which proves transactions with the specified
+    /// options.
+    ///
+    /// The service handles up to 4 connections concurrently, and uses a timeout of 30 seconds for
+    /// reading requests and writing responses.
+    pub fn new(proof_options: ProvingOptions) -> Self {
+        Self {
+            prover: TransactionProver::new(proof_options),
+            io_timeout: DEFAULT_IO_TIMEOUT,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+        }
+    }
+
+    /// Sets the timeout for reading a request from and writing a response to a connection, and
+    /// returns the resulting service.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_io_timeout(mut self, io_timeout: Duration) -> Self {
+        assert!(!io_timeout.is_zero(), "I/O timeout must be greater than zero");
+        self.io_timeout = io_timeout;
+        self
+    }
+
+    /// Sets the maximum number of connections which are handled concurrently, and returns the
+    /// resulting service.
+    ///
+    /// # Panics
+    /// Panics if the maximum number of connections is zero.
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        assert!(max_connections > 0, "maximum number of connections must be greater than zero");
+        self.max_connections = max_connections;
+        self
+    }
+
+    // SERVICE
+    // --------------------------------------------------------------------------------------------
+
+    /// Binds to the specified address and serves proving requests until an I/O error occurs on
+    /// the listener.
+    pub fn bind_and_serve(&self, address: impl ToSocketAddrs) -> std::io::Result<()> {
+        self.serve(TcpListener::bind(address)?)
+    }
+
+    /// Serves proving requests arriving at the provided listener until an I/O error occurs on
+    /// the listener.
+    ///
+    /// Failures of individual connections are not propagated, and the service keeps accepting
+    /// new connections after them. When the listener fails, the connections which are already
+    /// being handled are completed before the error is returned.
+    pub fn serve(&self, listener: TcpListener) -> std::io::Result<()> {
+        // the channel has no buffer, and so a connection is accepted only once a worker is ready
+        // to handle it
+        let (sender, receiver) = mpsc::sync_channel(0);
+        let receiver = Mutex::new(receiver);
+
+        thread::scope(|scope| {
+            for _ in 0..self.max_connections {
+                scope.spawn(|| self.handle_connections(&receiver));
+            }
+
+            // the sender is dropped once the listener fails, which stops the workers after they
+            // complete the connections they are handling
+            accept_connections(&listener, sender)
+        })
+    }
+
+    /// Reads a single proving request from the provided stream and writes back the response.
+    ///
+    /// Reading the request and writing the response time out after the configured I/O timeout.
+    pub fn handle_connection(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(self.io_timeout))?;
+        stream.set_write_timeout(Some(self.io_timeout))?;
+
+        let request = read_message(&mut stream)?;
+
+        let result = TransactionWitness::read_from_bytes(&request)
+            .map_err(|err| format!("invalid transaction witness: {err}"))
+            .and_then(|tx_witness| {
+                self.prover.prove_transaction(tx_witness).map_err(|err| err.to_string())
+            });
+
+        match result {
+            Ok(proven_tx) => {
+                stream.write_all(&[STATUS_OK])?;
+                write_message(&mut stream, &proven_tx.to_bytes())
+            },
+            Err(err) => {
+                stream.write_all(&[STATUS_ERROR])?;
+                write_message(&mut stream, err.as_bytes())
+            },
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles connections received from the provided channel until the channel is closed.
+    fn handle_connections(&self, connections: &Mutex<Receiver<TcpStream>>) {
+        loop {
+            // the lock is released before the connection is handled, so that other workers can
+            // receive connections in the meantime
+            let stream = match connections.lock().expect("connection receiver poisoned").recv() {
+                Ok(stream) => stream,
+                Err(_) => return,
+            };
+
+            // a client which disconnects early must not bring the worker down
+            let _ = self.handle_connection(stream);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Passes the connections accepted by the listener to the workers until an I/O error occurs on
+/// the listener.
+fn accept_connections(
+    listener: &TcpListener,
+    workers: SyncSender<TcpStream>,
+) -> std::io::Result<()> {
+    for stream in listener.incoming() {
+        // sending fails only if all workers panicked, in which case the panic is propagated when
+        // the scope of the workers ends
+        if workers.send(stream?).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the provided bytes to the stream prefixed with their length.
+fn write_message(stream: &mut TcpStream, bytes: &[u8]) -> std::io::Result<()> {
+    stream.write_all(&(bytes.len() as u64).to_le_bytes())?;
+    stream.write_all(bytes)?;
+    stream.flush()
+}
+
+/// Reads a length-prefixed message from the stream.
+fn read_message(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut len = [0_u8; 8];
+    stream.read_exact(&mut len)?;
+    let len = u64::from_le_bytes(len);
+    if len > MAX_MESSAGE_SIZE as u64 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("message size {len} exceeds maximum of {MAX_MESSAGE_SIZE} bytes"),
+        ));
+    }
+
+    let mut bytes = vec![0_u8; len as usize];
+    stream.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn connection_error(err: std::io::Error) -> TransactionProverError {
+    TransactionProverError::RemoteProverConnectionFailed(err.to_string())
+}
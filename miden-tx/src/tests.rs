@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::transaction::{ToTransactionKernelInputs, TransactionEvent, TransactionKernel};
 use miden_objects::{
     accounts::{
         Account, AccountCode, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -11,7 +11,8 @@ use miden_objects::{
     block::BlockHeader,
     notes::{NoteId, NoteType},
     transaction::{
-        ChainMmr, InputNote, InputNotes, ProvenTransaction, TransactionArgs, TransactionWitness,
+        ChainMmr, InputNote, InputNotes, ProvenTransaction, ProvenTransactionBuilder,
+        TransactionArgs, TransactionWitness,
     },
     Felt, Word,
 };
@@ -20,8 +21,10 @@ use mock::{
     constants::{non_fungible_asset, FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL},
     mock::{
         account::{
-            MockAccountType, ACCOUNT_INCR_NONCE_MAST_ROOT, ACCOUNT_SET_CODE_MAST_ROOT,
-            ACCOUNT_SET_ITEM_MAST_ROOT, STORAGE_INDEX_0,
+            MockAccountType, ACCOUNT_INCR_NONCE_MAST_ROOT, ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT,
+            ACCOUNT_SET_CODE_MAST_ROOT, ACCOUNT_SET_ITEM_MAST_ROOT, ACCOUNT_SET_MAP_ITEM_MAST_ROOT,
+            STORAGE_ELEMENTS_3, STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_INDEX_3,
+            STORAGE_LEAVES_2,
         },
         notes::AssetPreservationStatus,
         transaction::mock_inputs,
@@ -30,12 +33,13 @@ use mock::{
 };
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    MemAdviceProvider,
+    ExecutionError, MemAdviceProvider,
 };
 
 use super::{
-    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionHost, TransactionInputs,
-    TransactionProver, TransactionVerifier,
+    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionExecutorError,
+    TransactionHost, TransactionInputs, TransactionProver, TransactionVerifier,
+    TransactionVerifierError,
 };
 
 // TESTS
@@ -60,12 +64,16 @@ fn transaction_executor_witness() {
     // use the witness to execute the transaction again
     let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
     let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
-    let mut host = TransactionHost::new(tx_witness.account().into(), mem_advice_provider);
-    let result =
-        vm_processor::execute(tx_witness.program(), stack_inputs, &mut host, Default::default())
-            .unwrap();
+    let mut host = TransactionHost::new(tx_witness.account().into(), mem_advice_provider, None);
+    let result = vm_processor::execute(
+        executed_transaction.program(),
+        stack_inputs,
+        &mut host,
+        Default::default(),
+    )
+    .unwrap();
 
-    let (advice_provider, _, output_notes) = host.into_parts();
+    let (advice_provider, _, output_notes, _) = host.into_parts();
     let (_, map, _) = advice_provider.into_parts();
     let tx_outputs = TransactionKernel::from_transaction_parts(
         result.stack_outputs(),
@@ -78,6 +86,155 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_executor_measurements() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let (executed_transaction, measurements) = executor
+        .execute_transaction_with_measurements(
+            account_id,
+            block_ref,
+            &note_ids,
+            data_store.tx_args().clone(),
+        )
+        .unwrap();
+
+    // every phase of the transaction kernel must be measured
+    assert!(measurements.prologue > 0);
+    assert!(measurements.tx_script_processing > 0);
+    assert!(measurements.epilogue > 0);
+    assert_eq!(
+        measurements
+            .note_execution
+            .iter()
+            .map(|(note_id, _)| *note_id)
+            .collect::<Vec<_>>(),
+        note_ids
+    );
+    assert!(measurements.note_execution.iter().all(|(_, cycles)| *cycles > 0));
+
+    let note_execution_cycles: usize =
+        measurements.note_execution.iter().map(|(_, cycles)| cycles).sum();
+    assert!(measurements.notes_processing > note_execution_cycles);
+    assert!(
+        measurements.prologue
+            + measurements.notes_processing
+            + measurements.tx_script_processing
+            + measurements.epilogue
+            <= measurements.total_cycles
+    );
+
+    // each created note must be reflected in the recorded events
+    let num_created_notes = measurements
+        .events
+        .iter()
+        .filter(|event| **event == TransactionEvent::NoteCreated)
+        .count();
+    assert_eq!(num_created_notes, executed_transaction.output_notes().num_notes());
+
+    // executing the transaction with measurements must produce the same result
+    let expected_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
+}
+
+#[test]
+fn transaction_executor_measurements_of_failed_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the transaction script fails after all notes were consumed
+    let tx_script_code = ProgramAst::parse("begin push.0 assert end").unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let error = executor
+        .execute_transaction_with_measurements(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap_err();
+    let TransactionExecutorError::MeasuredExecutionFailed { error, measurements, progress } = error
+    else {
+        panic!("expected the measurements of the failed execution, got {error:?}");
+    };
+
+    // the error of the failed execution is the same as the one returned without measurements
+    let expected_error = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(*error, expected_error);
+    assert!(matches!(
+        expected_error,
+        TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { .. }
+        )
+    ));
+
+    // the phases executed before the failure are measured, and the failed phase is not completed
+    assert!(measurements.prologue > 0);
+    assert!(measurements.notes_processing > 0);
+    assert_eq!(measurements.note_execution.len(), note_ids.len());
+    assert!(measurements.total_cycles > measurements.prologue + measurements.notes_processing);
+    assert!(progress.tx_script_processing().start().is_some());
+    assert!(progress.tx_script_processing().end().is_none());
+    assert_eq!(measurements.tx_script_processing, 0);
+    assert_eq!(measurements.epilogue, 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn transaction_executor_async() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    block_on(executor.load_account_async(account_id)).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the futures returned by the data store can be driven by multi-threaded executors
+    fn assert_send<T: Send>(_: T) {}
+    assert_send(super::AsyncDataStore::get_account_code(&data_store, account_id));
+    assert_send(super::AsyncDataStore::get_transaction_inputs(
+        &data_store,
+        account_id,
+        block_ref,
+        &note_ids,
+    ));
+
+    let executed_transaction = block_on(executor.execute_transaction_async(
+        account_id,
+        block_ref,
+        &note_ids,
+        data_store.tx_args().clone(),
+    ))
+    .unwrap();
+
+    // executing the same transaction synchronously must produce the same result
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let expected_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
+    assert_eq!(executed_transaction.account_delta(), expected_transaction.account_delta());
+}
+
 #[test]
 fn executed_transaction_account_delta() {
     let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
@@ -97,6 +254,14 @@ fn executed_transaction_account_delta() {
     // updated storage
     let updated_slot_value = [Felt::new(7), Felt::new(9), Felt::new(11), Felt::new(13)];
 
+    // updated storage map
+    let updated_map_key = STORAGE_LEAVES_2[0].0;
+    let updated_map_value = [Felt::new(14), Felt::new(15), Felt::new(16), Felt::new(17)];
+
+    // updated storage array
+    let updated_array_position = STORAGE_ELEMENTS_3[0].0;
+    let updated_array_value = [Felt::new(18), Felt::new(19), Felt::new(20), Felt::new(21)];
+
     // removed assets
     let removed_asset_1 = Asset::Fungible(
         FungibleAsset::new(
@@ -131,6 +296,22 @@ fn executed_transaction_account_delta() {
             # => [R', V]
         end
 
+        proc.set_map_item
+            push.0 movdn.9
+            # => [index, KEY, NEW_VALUE, 0]
+
+            call.{ACCOUNT_SET_MAP_ITEM_MAST_ROOT}
+            # => [OLD_MAP_ROOT, OLD_VALUE]
+        end
+
+        proc.set_array_item
+            push.0 movdn.6 push.0 movdn.6
+            # => [index, position, NEW_VALUE, 0, 0]
+
+            call.{ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT}
+            # => [OLD_ARRAY_ROOT, OLD_VALUE]
+        end
+
         proc.set_code
             call.{ACCOUNT_SET_CODE_MAST_ROOT}
             # => [0, 0, 0, 0]
@@ -164,6 +345,38 @@ fn executed_transaction_account_delta() {
             exec.set_item dropw dropw
             # => []
 
+            ## Update account storage map
+            ## ------------------------------------------------------------------------------------
+            # push a new VALUE for the storage map onto the stack
+            push.{UPDATED_MAP_VALUE}
+            # => [17, 16, 15, 14]
+
+            # push a KEY for the storage map onto the stack
+            push.{UPDATED_MAP_KEY}
+            # => [KEY, 17, 16, 15, 14]
+
+            # get the index of account storage slot
+            push.{STORAGE_INDEX_2}
+            # => [idx, KEY, 17, 16, 15, 14]
+
+            # update the storage map value
+            exec.set_map_item dropw dropw
+            # => []
+
+            ## Update account storage array
+            ## ------------------------------------------------------------------------------------
+            # push a new VALUE for the storage array onto the stack
+            push.{UPDATED_ARRAY_VALUE}
+            # => [21, 20, 19, 18]
+
+            # push the position of the array element and the index of account storage slot
+            push.{UPDATED_ARRAY_POSITION} push.{STORAGE_INDEX_3}
+            # => [idx, position, 21, 20, 19, 18]
+
+            # update the storage array element
+            exec.set_array_item dropw dropw
+            # => []
+
             ## Send some assets from the account vault
             ## ------------------------------------------------------------------------------------
             # partially deplete fungible asset balance
@@ -203,6 +416,10 @@ fn executed_transaction_account_delta() {
     ",
         NEW_ACCOUNT_ROOT = prepare_word(&new_acct_code.root()),
         UPDATED_SLOT_VALUE = prepare_word(&Word::from(updated_slot_value)),
+        UPDATED_MAP_VALUE = prepare_word(&Word::from(updated_map_value)),
+        UPDATED_MAP_KEY = prepare_word(&Word::from(updated_map_key)),
+        UPDATED_ARRAY_VALUE = prepare_word(&updated_array_value),
+        UPDATED_ARRAY_POSITION = updated_array_position,
         REMOVED_ASSET_1 = prepare_word(&Word::from(removed_asset_1)),
         REMOVED_ASSET_2 = prepare_word(&Word::from(removed_asset_2)),
         REMOVED_ASSET_3 = prepare_word(&Word::from(removed_asset_3)),
@@ -238,6 +455,28 @@ fn executed_transaction_account_delta() {
         updated_slot_value
     );
 
+    assert_eq!(executed_transaction.account_delta().storage().updated_maps.len(), 1);
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_maps[0].0,
+        STORAGE_INDEX_2
+    );
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_maps[0].1.updated_leaves,
+        vec![(Word::from(updated_map_key), updated_map_value)]
+    );
+
+    assert_eq!(executed_transaction.account_delta().storage().updated_arrays.len(), 1);
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_arrays[0].0,
+        STORAGE_INDEX_3
+    );
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_arrays[0]
+            .1
+            .updated_elements,
+        vec![(updated_array_position, updated_array_value)]
+    );
+
     // vault delta
     // --------------------------------------------------------------------------------------------
     // assert that added assets are tracked
@@ -253,24 +492,22 @@ fn executed_transaction_account_delta() {
     assert!(executed_transaction
         .account_delta()
         .vault()
-        .added_assets
-        .iter()
-        .all(|x| added_assets.contains(x)));
+        .added_assets()
+        .all(|x| added_assets.contains(&x)));
     assert_eq!(
         added_assets.len(),
-        executed_transaction.account_delta().vault().added_assets.len()
+        executed_transaction.account_delta().vault().added_assets().count()
     );
 
     // assert that removed assets are tracked
     assert!(executed_transaction
         .account_delta()
         .vault()
-        .removed_assets
-        .iter()
-        .all(|x| removed_assets.contains(x)));
+        .removed_assets()
+        .all(|x| removed_assets.contains(&x)));
     assert_eq!(
         removed_assets.len(),
-        executed_transaction.account_delta().vault().removed_assets.len()
+        executed_transaction.account_delta().vault().removed_assets().count()
     );
 }
 
@@ -289,9 +526,20 @@ fn prove_witness_and_verify() {
         .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
         .unwrap();
 
+    let executed_transaction_id = executed_transaction.id();
+
+    // the witness can be proven after a serialization round trip, e.g., by a remote prover, in
+    // which case the transaction program is rebuilt from the witness
+    let tx_witness: TransactionWitness = executed_transaction.into();
+    assert!(tx_witness.program().is_some());
+    let tx_witness = TransactionWitness::read_from_bytes(&tx_witness.to_bytes()).unwrap();
+    assert!(tx_witness.program().is_none());
+
     let proof_options = ProvingOptions::default();
     let prover = TransactionProver::new(proof_options);
-    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let proven_transaction = prover.prove_transaction(tx_witness).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    assert_eq!(proven_transaction.expiration_block_num(), u32::MAX);
 
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
@@ -300,6 +548,100 @@ fn prove_witness_and_verify() {
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
+#[test]
+fn prove_and_verify_expiring_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+    use.miden::tx
+
+    begin
+        push.25 exec.tx::update_expiration_block_num
+    end
+    ",
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert_eq!(executed_transaction.expiration_block_num(), block_ref + 25);
+
+    let proof_options = ProvingOptions::default();
+    let prover = TransactionProver::new(proof_options);
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    assert_eq!(proven_transaction.expiration_block_num(), block_ref + 25);
+
+    let serialised_transaction = proven_transaction.to_bytes();
+    let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+
+    // the proof does not hold for a transaction claiming a different expiration block number
+    let tampered_transaction = with_expiration_block_num(&proven_transaction, u32::MAX);
+    assert!(matches!(
+        verifier.verify(tampered_transaction),
+        Err(TransactionVerifierError::TransactionVerificationFailed(_))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn prove_and_verify_batch() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    let prover = TransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let tampered_transaction = with_expiration_block_num(&proven_transaction, block_ref + 1);
+
+    // the batch contains more transactions than there are verification threads, and thus each
+    // thread verifies a chunk of several transactions when the `concurrent` feature is enabled
+    let thread_count = std::thread::available_parallelism().map_or(1, |v| v.get());
+    let is_tampered = |idx: usize| idx % 3 == 1;
+    let transactions: Vec<ProvenTransaction> = (0..2 * thread_count + 3)
+        .map(|idx| match is_tampered(idx) {
+            true => tampered_transaction.clone(),
+            false => proven_transaction.clone(),
+        })
+        .collect();
+
+    // the results are returned in the order of the provided transactions
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let results = verifier.verify_batch(&transactions);
+    assert_eq!(results.len(), transactions.len());
+    for (idx, result) in results.iter().enumerate() {
+        match is_tampered(idx) {
+            true => assert!(
+                matches!(result, Err(TransactionVerifierError::TransactionVerificationFailed(_))),
+                "transaction {idx} should fail verification"
+            ),
+            false => assert!(result.is_ok(), "transaction {idx} should pass verification"),
+        }
+    }
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -424,3 +766,188 @@ impl DataStore for MockDataStore {
         Ok(self.account.code().module().clone())
     }
 }
+
+#[cfg(feature = "async")]
+impl super::AsyncDataStore for MockDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        notes: &[NoteId],
+    ) -> impl core::future::Future<Output = Result<TransactionInputs, DataStoreError>> + Send {
+        core::future::ready(DataStore::get_transaction_inputs(self, account_id, block_num, notes))
+    }
+
+    fn get_account_code(
+        &self,
+        account_id: AccountId,
+    ) -> impl core::future::Future<Output = Result<ModuleAst, DataStoreError>> + Send {
+        core::future::ready(DataStore::get_account_code(self, account_id))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a copy of the provided transaction which claims the specified expiration block number,
+/// and whose proof is thus no longer valid.
+fn with_expiration_block_num(
+    transaction: &ProvenTransaction,
+    expiration_block_num: u32,
+) -> ProvenTransaction {
+    let mut builder = ProvenTransactionBuilder::new(
+        transaction.account_id(),
+        transaction.initial_account_hash(),
+        transaction.final_account_hash(),
+        transaction.block_ref(),
+        expiration_block_num,
+        transaction.proof().clone(),
+    )
+    .add_input_notes(transaction.input_notes().iter().copied())
+    .add_output_notes(transaction.output_notes().iter().cloned());
+    if let Some(tx_script_root) = transaction.tx_script_root() {
+        builder = builder.tx_script_root(tx_script_root);
+    }
+    if let Some(account_details) = transaction.account_details() {
+        builder = builder.account_details(account_details.clone());
+    }
+    builder.build().unwrap()
+}
+
+/// Drives the provided future to completion on the current thread.
+///
+/// The mock data store never suspends, and thus the future is expected to complete after being
+/// polled once.
+#[cfg(feature = "async")]
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    use alloc::{sync::Arc, task::Wake};
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future was not expected to suspend"),
+    }
+}
+
+#[cfg(feature = "remote-prover")]
+#[test]
+fn prove_witness_remotely() {
+    use alloc::string::ToString;
+    use std::net::TcpListener;
+
+    use super::{ProveTransaction, RemoteTransactionProver, TransactionProverService};
+
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+    let executed_transaction_id = executed_transaction.id();
+
+    // start the prover service on a free local port
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        TransactionProverService::new(ProvingOptions::default())
+            .serve(listener)
+            .unwrap()
+    });
+
+    // the remote prover can be used wherever a prover is expected
+    let prover: &dyn ProveTransaction = &RemoteTransactionProver::new(address.to_string());
+    let proven_transaction = prover.prove_transaction(executed_transaction.into()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
+#[cfg(feature = "remote-prover")]
+#[test]
+fn remote_prover_times_out_on_stalled_service() {
+    use alloc::string::ToString;
+    use std::{net::TcpListener, time::Duration};
+
+    use super::{RemoteTransactionProver, TransactionProverError};
+
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
+        .unwrap();
+
+    // the listener accepts connections into its backlog, but never responds to requests
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let prover = RemoteTransactionProver::new(address.to_string())
+        .with_connect_timeout(Duration::from_secs(1))
+        .with_io_timeout(Duration::from_millis(100));
+    let result = prover.prove_transaction(executed_transaction);
+    assert!(matches!(result, Err(TransactionProverError::RemoteProverConnectionFailed(_))));
+
+    drop(listener);
+}
+
+#[cfg(feature = "remote-prover")]
+#[test]
+fn remote_prover_service_releases_stalled_connections() {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        time::Duration,
+    };
+
+    use super::TransactionProverService;
+
+    // the service handles a single connection at a time and gives up on idle clients quickly
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        TransactionProverService::new(ProvingOptions::default())
+            .with_io_timeout(Duration::from_millis(100))
+            .with_max_connections(1)
+            .serve(listener)
+            .unwrap()
+    });
+
+    // a client which connects but never sends a request occupies the only worker
+    let _stalled_client = TcpStream::connect(address).unwrap();
+
+    // the request of the next client is handled once the stalled connection times out, and an
+    // invalid witness is answered with an error response
+    let mut client = TcpStream::connect(address).unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+    let request = [1_u8, 2, 3];
+    client.write_all(&(request.len() as u64).to_le_bytes()).unwrap();
+    client.write_all(&request).unwrap();
+
+    let mut status = [0_u8];
+    client.read_exact(&mut status).unwrap();
+    assert_eq!(status[0], 1);
+}
@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+#[cfg(feature = "concurrent")]
+use std::thread;
+
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
 use miden_verifier::verify;
@@ -31,6 +35,51 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_transaction(&transaction)
+    }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel and returns the
+    /// verification result of each transaction, in the order of the provided transactions.
+    ///
+    /// When the `concurrent` feature is enabled, the transactions are verified in parallel.
+    pub fn verify_batch(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Vec<Result<(), TransactionVerifierError>> {
+        #[cfg(feature = "concurrent")]
+        {
+            let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
+            let chunk_size = transactions.len().div_ceil(thread_count).max(1);
+
+            thread::scope(|scope| {
+                let handles: Vec<_> = transactions
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || {
+                            chunk.iter().map(|tx| self.verify_transaction(tx)).collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("verification thread panicked"))
+                    .collect()
+            })
+        }
+
+        #[cfg(not(feature = "concurrent"))]
+        transactions.iter().map(|tx| self.verify_transaction(tx)).collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel.
+    fn verify_transaction(
+        &self,
+        transaction: &ProvenTransaction,
+    ) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -42,6 +91,7 @@ impl TransactionVerifier {
             transaction.final_account_hash(),
             transaction.output_notes().commitment(),
             transaction.tx_script_root(),
+            transaction.expiration_block_num(),
         );
 
         // verify transaction proof
@@ -39,6 +39,7 @@ use vm_processor::utils::Deserializable;
 #[derive(Clone)]
 pub struct MockDataStore {
     pub account: Account,
+    pub account_seed: Option<Word>,
     pub block_header: BlockHeader,
     pub block_chain: ChainMmr,
     pub notes: Vec<InputNote>,
@@ -52,6 +53,7 @@ impl MockDataStore {
         let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
         Self {
             account,
+            account_seed: None,
             block_header,
             block_chain,
             notes: notes.into_vec(),
@@ -82,6 +84,7 @@ impl MockDataStore {
 
         Self {
             account,
+            account_seed: None,
             block_header,
             block_chain,
             notes: consumed_notes,
@@ -105,7 +108,12 @@ impl DataStore for MockDataStore {
     ) -> Result<TransactionInputs, DataStoreError> {
         assert_eq!(account_id, self.account.id());
         assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
+        if let Some(note_id) = notes
+            .iter()
+            .find(|note_id| self.notes.iter().all(|note| note.id() != **note_id))
+        {
+            return Err(DataStoreError::NoteNotFound(*note_id));
+        }
 
         let notes = self
             .notes
@@ -116,7 +124,7 @@ impl DataStore for MockDataStore {
 
         Ok(TransactionInputs::new(
             self.account.clone(),
-            None,
+            self.account_seed,
             self.block_header,
             self.block_chain.clone(),
             InputNotes::new(notes).unwrap(),
@@ -1,6 +1,6 @@
 use miden_lib::{
     accounts::faucets::create_basic_fungible_faucet,
-    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, KernelAssertionError, TransactionKernel},
     AuthScheme,
 };
 use miden_objects::{
@@ -15,7 +15,7 @@ use miden_objects::{
     transaction::TransactionArgs,
     Felt, Word, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
 use mock::utils::prepare_word;
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
@@ -154,7 +154,13 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     let executed_transaction =
         executor.execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args);
 
-    assert!(executed_transaction.is_err());
+    assert!(matches!(
+        executed_transaction,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::BasicFungibleMaxSupplyOverflow,
+            ..
+        })
+    ));
 }
 
 // TESTS BURN FUNGIBLE ASSET
@@ -275,6 +281,41 @@ fn faucet_contract_creation() {
     assert_eq!(faucet_account.code(), &exp_faucet_account_code);
 }
 
+#[test]
+fn faucet_contract_creation_with_no_auth() {
+    let token_symbol = TokenSymbol::try_from("POL").unwrap();
+
+    let (faucet_account, _) = create_basic_fungible_faucet(
+        [1_u8; 32],
+        token_symbol,
+        2,
+        Felt::new(123),
+        AccountStorageType::OffChain,
+        AuthScheme::NoAuth,
+    )
+    .unwrap();
+
+    assert!(faucet_account.is_faucet());
+    assert_eq!(faucet_account.storage().get_item(0), [ZERO; 4].into());
+
+    // the faucet exposes the faucet procedures together with the no-auth procedure
+    let exp_faucet_account_code_ast = ModuleAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+        use.miden::contracts::auth::basic
+
+        export.faucet::distribute
+        export.faucet::burn
+        export.basic::auth_tx_no_auth
+        ",
+    )
+    .unwrap();
+    let exp_faucet_account_code =
+        AccountCode::new(exp_faucet_account_code_ast, &TransactionKernel::assembler()).unwrap();
+
+    assert_eq!(faucet_account.code().root(), exp_faucet_account_code.root());
+}
+
 fn get_faucet_account_with_max_supply_and_total_issuance(
     public_key: Word,
     max_supply: u64,
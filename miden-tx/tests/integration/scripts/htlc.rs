@@ -0,0 +1,119 @@
+use miden_lib::{notes::create_htlc_note, transaction::KernelAssertionError};
+use miden_objects::{
+    accounts::{
+        AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
+    },
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteType},
+    Felt, Hasher, Word, ZERO,
+};
+use miden_tx::TransactionExecutorError;
+
+use super::{assert_empty_account_received_assets, consume_note_with_empty_account};
+use crate::prove_and_verify_transaction;
+
+// HTLC TESTS
+// ===============================================================================================
+// We want to test the hash time-locked contract script, which is a script that allows the target
+// account to consume the note by revealing the preimage of a secret hash, and the sender account
+// to reclaim the note after the specified block height (Note: current block height is 4).
+
+const SECRET: Word = [Felt::new(11), Felt::new(22), Felt::new(33), Felt::new(44)];
+
+#[test]
+fn prove_htlc_script_claim() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let malicious_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+
+    let note = create_note(sender_account_id, target_account_id, 5);
+
+    // the target can consume the note by providing the secret
+    let executed_transaction =
+        consume_note_with_empty_account(target_account_id, &note, Some(SECRET)).unwrap();
+    assert_empty_account_received_assets(&executed_transaction, target_account_id, &[get_asset()]);
+    assert!(prove_and_verify_transaction(executed_transaction).is_ok());
+
+    // the target can not consume the note without the secret
+    let wrong_secret = [Felt::new(11), Felt::new(22), Felt::new(33), ZERO];
+    let result = consume_note_with_empty_account(target_account_id, &note, Some(wrong_secret));
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::HtlcInvalidSecret,
+            ..
+        })
+    ));
+
+    // the sender can not reclaim the note before the reclaim block height
+    let result = consume_note_with_empty_account(sender_account_id, &note, Some(SECRET));
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::HtlcReclaimHeightNotReached,
+            ..
+        })
+    ));
+
+    // other accounts can not consume the note, even when they know the secret
+    let result = consume_note_with_empty_account(malicious_account_id, &note, Some(SECRET));
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::HtlcReclaimAcctIsNotSender,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn htlc_script_refund() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let malicious_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+
+    let note = create_note(sender_account_id, target_account_id, 3);
+
+    // the sender can reclaim the note after the reclaim block height without the secret
+    let executed_transaction =
+        consume_note_with_empty_account(sender_account_id, &note, Some(Word::default())).unwrap();
+    assert_empty_account_received_assets(&executed_transaction, sender_account_id, &[get_asset()]);
+
+    // other accounts still can not consume the note
+    let result = consume_note_with_empty_account(malicious_account_id, &note, Some(SECRET));
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::HtlcReclaimAcctIsNotSender,
+            ..
+        })
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn get_asset() -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, 100).unwrap().into()
+}
+
+fn create_note(sender: AccountId, target: AccountId, recall_height: u32) -> Note {
+    create_htlc_note(
+        sender,
+        target,
+        vec![get_asset()],
+        NoteType::Public,
+        Hasher::hash_elements(&SECRET),
+        recall_height,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap()
+}
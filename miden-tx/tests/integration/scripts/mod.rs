@@ -1,4 +1,73 @@
+use std::collections::BTreeMap;
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault},
+    notes::Note,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    vm::AdviceMap,
+    Felt, Word,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map, MockDataStore,
+};
+
 mod faucet;
+mod htlc;
 mod p2id;
 mod p2idr;
+mod p2idt;
+mod partial_swap;
 mod swap;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction in which an empty account with the specified ID and the default account
+/// code consumes the note, providing the specified note args, if any.
+fn consume_note_with_empty_account(
+    account_id: AccountId,
+    note: &Note,
+    note_args: Option<Word>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let (pub_key, keypair_felt) = get_new_key_pair_with_advice_map();
+    let account = get_account_with_default_account_code(account_id, pub_key, None);
+
+    let data_store = MockDataStore::with_existing(Some(account), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felt)], vec![])
+        .unwrap();
+    let note_args = note_args.map(|note_args| BTreeMap::from([(note.id(), note_args)]));
+    let tx_args = TransactionArgs::new(Some(tx_script), note_args, AdviceMap::default());
+
+    let block_ref = data_store.block_header.block_num();
+    executor.execute_transaction(account_id, block_ref, &[note.id()], tx_args)
+}
+
+/// Asserts that the account created by [consume_note_with_empty_account()] received the specified
+/// assets and its nonce was incremented.
+fn assert_empty_account_received_assets(
+    executed_transaction: &ExecutedTransaction,
+    account_id: AccountId,
+    assets: &[Asset],
+) {
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let account = get_account_with_default_account_code(account_id, pub_key, None);
+
+    let account_after = Account::new(
+        account_id,
+        AssetVault::new(assets).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+}
@@ -1,4 +1,9 @@
-use miden_lib::{notes::create_p2id_note, transaction::TransactionKernel};
+use std::collections::BTreeMap;
+
+use miden_lib::{
+    notes::create_p2id_note,
+    transaction::{KernelAssertionError, TransactionKernel},
+};
 use miden_objects::{
     accounts::{
         Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -13,12 +18,15 @@ use miden_objects::{
     transaction::TransactionArgs,
     Felt,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{
+    DataStoreError, NoteConsumptionChecker, NoteConsumptionStatus, TransactionCompilerError,
+    TransactionExecutor, TransactionExecutorError,
+};
 use mock::mock::account::DEFAULT_AUTH_SCRIPT;
 
 use crate::{
     get_account_with_default_account_code, get_new_key_pair_with_advice_map,
-    prove_and_verify_transaction, MockDataStore,
+    get_note_with_fungible_asset_and_script, prove_and_verify_transaction, MockDataStore,
 };
 
 // P2ID TESTS
@@ -242,6 +250,148 @@ fn p2id_script_multiple_assets() {
     assert!(executed_transaction_2.is_err());
 }
 
+#[test]
+fn check_p2id_notes_consumability() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, _) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // a note targeting the account, a note targeting another account, and a note calling a
+    // procedure which is not a part of the account interface
+    let consumable_note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset.into()],
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let foreign_note = create_p2id_note(
+        sender_account_id,
+        other_account_id,
+        vec![fungible_asset.into()],
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+    )
+    .unwrap();
+    let incompatible_note_script = ProgramAst::parse(
+        "
+        use.miden::contracts::faucets::basic_fungible->faucet
+
+        begin
+            call.faucet::burn
+        end
+        ",
+    )
+    .unwrap();
+    let incompatible_note =
+        get_note_with_fungible_asset_and_script(fungible_asset, incompatible_note_script);
+
+    let data_store = MockDataStore::with_existing(
+        Some(target_account.clone()),
+        Some(vec![consumable_note.clone(), foreign_note.clone(), incompatible_note.clone()]),
+    );
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    // the transaction script does not need to provide the secret key of the account
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let checker = NoteConsumptionChecker::new(&executor);
+    let statuses = checker
+        .check_notes_consumability(target_account_id, block_ref, &data_store.notes, tx_args.clone())
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(statuses.len(), 3);
+
+    assert_eq!(statuses[&consumable_note.id()], NoteConsumptionStatus::Consumable);
+
+    let foreign_note_status = &statuses[&foreign_note.id()];
+    assert!(!foreign_note_status.is_consumable());
+    assert_eq!(
+        foreign_note_status.kernel_error(),
+        Some(KernelAssertionError::P2idTargetAcctMismatch)
+    );
+
+    assert!(matches!(
+        statuses[&incompatible_note.id()],
+        NoteConsumptionStatus::Unconsumable(TransactionExecutorError::CompileTransactionFailed(
+            TransactionCompilerError::NoteIncompatibleWithAccountInterface(_)
+        ))
+    ));
+}
+
+#[test]
+fn check_notes_consumability_with_unknown_note() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, _) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let notes = [[1, 2, 3, 4], [5, 6, 7, 8]].map(|seed| {
+        create_p2id_note(
+            sender_account_id,
+            target_account_id,
+            vec![fungible_asset.into()],
+            NoteType::Public,
+            RpoRandomCoin::new(seed.map(Felt::new)),
+        )
+        .unwrap()
+    });
+
+    // the second note is known only to another data store, and thus the inputs required to
+    // consume it can not be fetched from the data store of the executor
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![notes[0].clone()]));
+    let other_data_store = MockDataStore::with_existing(Some(target_account), Some(notes.to_vec()));
+    let input_notes = vec![data_store.notes[0].clone(), other_data_store.notes[1].clone()];
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let checker = NoteConsumptionChecker::new(&executor);
+    let statuses = checker.check_notes_consumability(
+        target_account_id,
+        data_store.block_header.block_num(),
+        &input_notes,
+        tx_args,
+    );
+
+    assert_eq!(
+        statuses,
+        vec![
+            (notes[0].id(), NoteConsumptionStatus::Consumable),
+            (
+                notes[1].id(),
+                NoteConsumptionStatus::FetchInputsFailed(DataStoreError::NoteNotFound(
+                    notes[1].id()
+                ))
+            ),
+        ]
+    );
+}
+
 #[test]
 fn test_note_script_to_from_felt() {
     let assembler = TransactionKernel::assembler();
@@ -1,4 +1,4 @@
-use miden_lib::notes::create_p2idr_note;
+use miden_lib::{notes::create_p2idr_note, transaction::KernelAssertionError};
 use miden_objects::{
     accounts::{
         Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -12,7 +12,7 @@ use miden_objects::{
     transaction::TransactionArgs,
     Felt,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
 use mock::mock::account::DEFAULT_AUTH_SCRIPT;
 
 use crate::{
@@ -154,7 +154,13 @@ fn p2idr_script() {
 
     // Check that we got the expected result - TransactionExecutorError and not ExecutedTransaction
     // Second transaction should not work (sender consumes too early), we expect an error
-    assert!(executed_transaction_2.is_err());
+    assert!(matches!(
+        executed_transaction_2,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::P2idrReclaimHeightNotReached,
+            ..
+        })
+    ));
 
     // CONSTRUCT AND EXECUTE TX (Case "in time" - Malicious Target Account Failure)
     // --------------------------------------------------------------------------------------------
@@ -0,0 +1,113 @@
+use miden_lib::{notes::create_p2idt_note, transaction::KernelAssertionError};
+use miden_objects::{
+    accounts::{
+        AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
+    },
+    assets::{Asset, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::NoteType,
+    Felt,
+};
+use miden_tx::TransactionExecutorError;
+use mock::mock::block::mock_block_header;
+
+use super::{assert_empty_account_received_assets, consume_note_with_empty_account};
+
+// P2IDT TESTS
+// ===============================================================================================
+// We want to test the Pay to ID Time-locked script, which is a script that allows the sender to
+// specify a timestamp before which the note can not be consumed by the target account, and a
+// timestamp starting from which the note can be consumed (reclaimed) by the sender account. The
+// timestamps are compared against the timestamp of the transaction's reference block (block 4).
+#[test]
+fn p2idt_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let malicious_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+
+    // the timestamp of the reference block used by the mock data store
+    let block_timestamp = mock_block_header(4, None, None, &[]).timestamp().as_int() as u32;
+    let create_note = |unlock_timestamp: u32, reclaim_timestamp: u32| {
+        create_p2idt_note(
+            sender_account_id,
+            target_account_id,
+            vec![fungible_asset],
+            NoteType::Public,
+            unlock_timestamp,
+            reclaim_timestamp,
+            RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        )
+        .unwrap()
+    };
+
+    // Case "locked": the note can not be consumed by anyone
+    // --------------------------------------------------------------------------------------------
+    let note_locked = create_note(block_timestamp + 1, block_timestamp + 100);
+
+    let result = consume_note_with_empty_account(target_account_id, &note_locked, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::P2idtUnlockTimestampNotReached,
+            ..
+        })
+    ));
+
+    let result = consume_note_with_empty_account(sender_account_id, &note_locked, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::P2idtReclaimTimestampNotReached,
+            ..
+        })
+    ));
+
+    // Case "unlocked": the note can be consumed by the target account only
+    // --------------------------------------------------------------------------------------------
+    let note_unlocked = create_note(block_timestamp, block_timestamp + 100);
+
+    let executed_transaction =
+        consume_note_with_empty_account(target_account_id, &note_unlocked, None).unwrap();
+    assert_empty_account_received_assets(
+        &executed_transaction,
+        target_account_id,
+        &[fungible_asset],
+    );
+
+    let result = consume_note_with_empty_account(malicious_account_id, &note_unlocked, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::P2idtReclaimAcctIsNotSender,
+            ..
+        })
+    ));
+
+    // Case "reclaimable": the note can be reclaimed by the sender account
+    // --------------------------------------------------------------------------------------------
+    let note_reclaimable = create_note(block_timestamp + 1, block_timestamp);
+
+    let executed_transaction =
+        consume_note_with_empty_account(sender_account_id, &note_reclaimable, None).unwrap();
+    assert_empty_account_received_assets(
+        &executed_transaction,
+        sender_account_id,
+        &[fungible_asset],
+    );
+
+    let result = consume_note_with_empty_account(malicious_account_id, &note_reclaimable, None);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::P2idtReclaimAcctIsNotSender,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use miden_lib::{
+    notes::{create_partial_swap_fill_notes, create_partial_swap_note},
+    transaction::KernelAssertionError,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteExecutionMode, NoteTag, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    vm::AdviceMap,
+    Felt, Word, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// PSWAP TESTS
+// ================================================================================================
+
+#[test]
+fn prove_partial_swap_script() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    // the sender offers 100 units of the first asset for 50 units of the second asset
+    let note = create_partial_swap_note(
+        sender_account_id,
+        offered_asset(100),
+        requested_asset(50),
+        NoteType::Public,
+        partial_swap_tag(),
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // the target provides 20 units of the requested asset, and thus should receive 40 units of
+    // the offered asset and create a payback note for the sender and a PSWAP note offering the
+    // remaining 60 units for the remaining 30 units
+    let (payback_note, remainder_note) =
+        create_partial_swap_fill_notes(&note, target_account_id, 20).unwrap();
+    let remainder_note = remainder_note.expect("partial fill creates a remainder note");
+    assert_eq!(remainder_note.assets().iter().next(), Some(&offered_asset(60)));
+    assert_eq!(remainder_note.metadata().tag(), partial_swap_tag());
+    let expected_notes = [payback_note, remainder_note.clone()];
+
+    let target_account = get_target_account(target_account_id, requested_asset(50));
+    let executed_transaction =
+        fill_partial_swap_note(&target_account, &note, 20, &expected_notes).unwrap();
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let target_account_after = Account::new(
+        target_account.id(),
+        AssetVault::new(&[offered_asset(40), requested_asset(30)]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+    assert_output_notes(&executed_transaction, &expected_notes);
+
+    // filling the remainder note in full creates only the payback note
+    let (payback_note, no_remainder_note) =
+        create_partial_swap_fill_notes(&remainder_note, target_account_id, 30).unwrap();
+    assert!(no_remainder_note.is_none());
+    let expected_notes = [payback_note];
+
+    let target_account = get_target_account(target_account_id, requested_asset(30));
+    let executed_transaction =
+        fill_partial_swap_note(&target_account, &remainder_note, 30, &expected_notes).unwrap();
+
+    let target_account_after = Account::new(
+        target_account.id(),
+        AssetVault::new(&[offered_asset(60)]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+    assert_output_notes(&executed_transaction, &expected_notes);
+}
+
+#[test]
+fn partial_swap_script_rounds_down_offered_amount() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    // the sender offers 100 units of the first asset for 30 units of the second asset
+    let note = create_partial_swap_note(
+        sender_account_id,
+        offered_asset(100),
+        requested_asset(30),
+        NoteType::Public,
+        partial_swap_tag(),
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // the target provides 7 units of the requested asset, which are worth 23.33 units of the
+    // offered asset, and thus should receive 23 units and leave the remaining 77 units in the
+    // remainder note
+    let (payback_note, remainder_note) =
+        create_partial_swap_fill_notes(&note, target_account_id, 7).unwrap();
+    let remainder_note = remainder_note.expect("partial fill creates a remainder note");
+    assert_eq!(remainder_note.assets().iter().next(), Some(&offered_asset(77)));
+    let expected_notes = [payback_note, remainder_note];
+
+    let target_account = get_target_account(target_account_id, requested_asset(30));
+    let executed_transaction =
+        fill_partial_swap_note(&target_account, &note, 7, &expected_notes).unwrap();
+
+    let target_account_after = Account::new(
+        target_account.id(),
+        AssetVault::new(&[offered_asset(23), requested_asset(23)]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+    assert_output_notes(&executed_transaction, &expected_notes);
+}
+
+#[test]
+fn partial_swap_script_invalid_fill_amount() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let note = create_partial_swap_note(
+        sender_account_id,
+        offered_asset(100),
+        requested_asset(50),
+        NoteType::Public,
+        partial_swap_tag(),
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let target_account = get_target_account(target_account_id, requested_asset(100));
+
+    // the fill amount can not exceed the requested amount
+    let result = fill_partial_swap_note(&target_account, &note, 51, &[]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::PswapInvalidFillAmount,
+            ..
+        })
+    ));
+
+    // the fill amount can not be zero
+    let result = fill_partial_swap_note(&target_account, &note, 0, &[]);
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::KernelAssertionFailed {
+            error: KernelAssertionError::PswapInvalidFillAmount,
+            ..
+        })
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn offered_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+fn requested_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+/// Returns the tag of the PSWAP notes, which is derived from the faucet of the offered asset.
+fn partial_swap_tag() -> NoteTag {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    NoteTag::from_account_id(faucet_id, NoteExecutionMode::Local).unwrap()
+}
+
+fn get_target_account(account_id: AccountId, asset: Asset) -> Account {
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    get_account_with_default_account_code(account_id, pub_key, Some(asset))
+}
+
+/// Executes a transaction in which the provided account consumes the PSWAP note providing the
+/// specified amount of the requested asset.
+fn fill_partial_swap_note(
+    account: &Account,
+    note: &Note,
+    fill_amount: u64,
+    expected_output_notes: &[Note],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    // the key pair is generated from a fixed seed, and thus matches the key of the account
+    let (pub_key, sk_felt) = get_new_key_pair_with_advice_map();
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, sk_felt)], vec![])
+        .unwrap();
+
+    let note_args: Word = [Felt::new(fill_amount), ZERO, ZERO, ZERO];
+    let mut tx_args = TransactionArgs::new(
+        Some(tx_script),
+        Some(BTreeMap::from([(note.id(), note_args)])),
+        AdviceMap::default(),
+    );
+    tx_args.extend_expected_output_notes(expected_output_notes.to_vec());
+
+    executor.execute_transaction(account.id(), block_ref, &[note.id()], tx_args)
+}
+
+fn assert_output_notes(executed_transaction: &ExecutedTransaction, expected_notes: &[Note]) {
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), expected_notes.len());
+    for (idx, expected_note) in expected_notes.iter().enumerate() {
+        let output_note = output_notes.get_note(idx);
+        assert_eq!(output_note.id(), expected_note.id());
+        assert_eq!(output_note.metadata(), expected_note.metadata());
+    }
+}
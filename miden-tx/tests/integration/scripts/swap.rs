@@ -1,4 +1,4 @@
-use miden_lib::notes::{create_swap_note, utils::build_p2id_recipient};
+use miden_lib::notes::create_swap_note;
 use miden_objects::{
     accounts::{
         Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
@@ -8,8 +8,8 @@ use miden_objects::{
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
     crypto::rand::RpoRandomCoin,
-    notes::{NoteAssets, NoteEnvelope, NoteExecutionMode, NoteId, NoteMetadata, NoteTag, NoteType},
-    transaction::TransactionArgs,
+    notes::{NoteDetails, NoteEnvelope, NoteExecutionMode, NoteMetadata, NoteTag, NoteType},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
     Felt, ZERO,
 };
 use miden_tx::TransactionExecutor;
@@ -22,7 +22,72 @@ use crate::{
 
 #[test]
 fn prove_swap_script() {
-    // Create assets
+    let (fungible_asset, non_fungible_asset) = get_swap_assets();
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let (executed_transaction, target_account, payback_note) =
+        execute_swap_transaction(NoteType::OffChain, false);
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // target account vault delta
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+
+    // Check that the target account has received the asset from the note
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // Check if only one `Note` has been created
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    // Check if the created `Note` is what we expect
+    assert_eq!(payback_note.assets().iter().next(), Some(&non_fungible_asset));
+    let tag = NoteTag::from_account_id(sender_account_id, NoteExecutionMode::Local).unwrap();
+    let note_metadata =
+        NoteMetadata::new(target_account_id, NoteType::OffChain, tag, ZERO).unwrap();
+
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(
+        NoteEnvelope::from(created_note),
+        NoteEnvelope::new(payback_note.id(), note_metadata).unwrap()
+    );
+}
+
+#[test]
+fn swap_script_public_payback_note() {
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let (executed_transaction, _, payback_note) = execute_swap_transaction(NoteType::Public, true);
+
+    // Check that the payback note has been created as a public note with the expected details
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    let tag = NoteTag::from_account_id(sender_account_id, NoteExecutionMode::Local).unwrap();
+    let note_metadata = NoteMetadata::new(target_account_id, NoteType::Public, tag, ZERO).unwrap();
+
+    match executed_transaction.output_notes().get_note(0) {
+        OutputNote::Public(note) => {
+            assert_eq!(note.id(), payback_note.id());
+            assert_eq!(note.metadata(), &note_metadata);
+            assert_eq!(NoteDetails::from(note), payback_note);
+        },
+        OutputNote::Private(_) => panic!("expected the payback note to be public"),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn get_swap_assets() -> (Asset, Asset) {
     let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
     let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
 
@@ -33,6 +98,20 @@ fn prove_swap_script() {
     .unwrap()
     .into();
 
+    (fungible_asset, non_fungible_asset)
+}
+
+/// Executes a transaction in which the target account consumes a SWAP note offering a fungible
+/// asset for a non-fungible asset, and whose payback note has the specified type.
+///
+/// If `add_payback_note` is true, the details of the payback note are provided to the transaction
+/// as an expected output note.
+fn execute_swap_transaction(
+    payback_note_type: NoteType,
+    add_payback_note: bool,
+) -> (ExecutedTransaction, Account, NoteDetails) {
+    let (fungible_asset, non_fungible_asset) = get_swap_assets();
+
     // Create sender and target account
     let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
 
@@ -46,11 +125,12 @@ fn prove_swap_script() {
     );
 
     // Create the note containing the SWAP script
-    let (note, repay_serial_num) = create_swap_note(
+    let (note, payback_note) = create_swap_note(
         sender_account_id,
         fungible_asset,
         non_fungible_asset,
         NoteType::Public,
+        payback_note_type,
         RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
     )
     .unwrap();
@@ -70,41 +150,14 @@ fn prove_swap_script() {
     let tx_script_target = executor
         .compile_tx_script(tx_script_code.clone(), vec![(target_pub_key, target_sk_felt)], vec![])
         .unwrap();
-    let tx_args_target = TransactionArgs::with_tx_script(tx_script_target);
+    let mut tx_args_target = TransactionArgs::with_tx_script(tx_script_target);
+    if add_payback_note {
+        tx_args_target.add_expected_output_note(payback_note.clone());
+    }
 
     let executed_transaction = executor
         .execute_transaction(target_account_id, block_ref, &note_ids, tx_args_target)
         .expect("Transaction consuming swap note failed");
 
-    // Prove, serialize/deserialize and verify the transaction
-    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
-
-    // target account vault delta
-    let target_account_after: Account = Account::new(
-        target_account.id(),
-        AssetVault::new(&[fungible_asset]).unwrap(),
-        target_account.storage().clone(),
-        target_account.code().clone(),
-        Felt::new(2),
-    );
-
-    // Check that the target account has received the asset from the note
-    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
-
-    // Check if only one `Note` has been created
-    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
-
-    // Check if the created `Note` is what we expect
-    let recipient = build_p2id_recipient(sender_account_id, repay_serial_num).unwrap();
-    let tag = NoteTag::from_account_id(sender_account_id, NoteExecutionMode::Local).unwrap();
-    let note_metadata =
-        NoteMetadata::new(target_account_id, NoteType::OffChain, tag, ZERO).unwrap();
-    let assets = NoteAssets::new(vec![non_fungible_asset]).unwrap();
-    let note_id = NoteId::new(recipient, assets.commitment());
-
-    let created_note = executed_transaction.output_notes().get_note(0);
-    assert_eq!(
-        NoteEnvelope::from(created_note),
-        NoteEnvelope::new(note_id, note_metadata).unwrap()
-    );
+    (executed_transaction, target_account, payback_note)
 }
@@ -1,18 +1,22 @@
+use std::sync::Arc;
+
 use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
 use miden_objects::{
     accounts::{
         Account, AccountId, AccountStorage, SlotItem, StorageSlot,
-        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
-        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::NoteType,
-    transaction::TransactionArgs,
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin, utils::Serializable},
+    notes::{NoteAssets, NoteId, NoteType},
+    transaction::{InputNotes, TransactionArgs},
     Felt, Word, ONE, ZERO,
 };
-use miden_tx::TransactionExecutor;
+use miden_tx::{
+    auth::BasicAuthenticator, AuthenticationError, TransactionExecutor, TransactionExecutorError,
+};
 use mock::{mock::account::DEFAULT_AUTH_SCRIPT, utils::prepare_word};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
@@ -101,6 +105,131 @@ fn prove_receive_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
 }
 
+#[test]
+/// Testing the basic Miden wallet - previewing the receipt of an asset without a signature
+fn simulate_receive_asset_via_wallet() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note_script_ast = ProgramAst::parse(
+        "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    # add the asset
+    begin
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+    ",
+    )
+    .unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, note_script_ast);
+
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the transaction script does not provide the secret key of the account
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let unsigned_tx_script =
+        executor.compile_tx_script(tx_script_code.clone(), vec![], vec![]).unwrap();
+    let unsigned_tx_args = TransactionArgs::with_tx_script(unsigned_tx_script);
+
+    // executing the transaction fails since the signature can not be provided
+    assert!(executor
+        .execute_transaction(target_account.id(), block_ref, &note_ids, unsigned_tx_args.clone())
+        .is_err());
+
+    // simulating the transaction succeeds
+    let simulated_transaction = executor
+        .simulate_transaction(target_account.id(), block_ref, &note_ids, unsigned_tx_args)
+        .unwrap();
+
+    // the simulation must have the same effects as the execution of the signed transaction
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_keypair_felt)], vec![])
+        .unwrap();
+    let executed_transaction = executor
+        .execute_transaction(
+            target_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap();
+
+    assert_eq!(simulated_transaction.account_id(), target_account.id());
+    assert_eq!(simulated_transaction.account_delta(), executed_transaction.account_delta());
+    assert_eq!(simulated_transaction.output_notes(), executed_transaction.output_notes());
+    assert!(simulated_transaction.cycle_count() > 0);
+}
+
+#[test]
+/// Testing the basic Miden wallet - previewing the receipt of an asset by a new account
+fn simulate_receive_asset_via_new_wallet() {
+    use miden_objects::accounts::{AccountStorageType, AccountType};
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([6_u8; 32]));
+    let (wallet, seed) = create_basic_wallet(
+        [6_u8; 32],
+        AuthScheme::RpoFalcon512 { pub_key: sec_key.public_key() },
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    assert!(wallet.is_new());
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, receive_asset_script());
+    let mut data_store = MockDataStore::with_existing(Some(wallet.clone()), Some(vec![note]));
+    data_store.account_seed = Some(seed);
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(wallet.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    // the public key of a new account can not be replaced, and thus the simulation fails without
+    // the signature of the account owner
+    assert!(executor
+        .simulate_transaction(wallet.id(), block_ref, &note_ids, tx_args.clone())
+        .is_err());
+
+    // the signature is provided by the authenticator of the executor
+    let authenticator = BasicAuthenticator::new(&[sec_key], RpoRandomCoin::new([ZERO; 4]));
+    let executor = executor.with_authenticator(Arc::new(authenticator));
+
+    let simulated_transaction = executor
+        .simulate_transaction(wallet.id(), block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    let executed_transaction = executor
+        .execute_transaction(wallet.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    assert_eq!(simulated_transaction.account_delta(), executed_transaction.account_delta());
+    assert_eq!(simulated_transaction.output_notes(), executed_transaction.output_notes());
+}
+
 #[test]
 /// Testing the basic Miden wallet - sending an asset
 fn prove_send_asset_via_wallet() {
@@ -182,6 +311,176 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+/// Testing the basic Miden wallet - sending multiple assets in a single note
+fn prove_send_multiple_assets_via_wallet() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let fungible_asset_2: Asset = FungibleAsset::new(faucet_id_2, 50).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account =
+        get_account_with_default_account_code(sender_account_id, sender_pub_key, None);
+    let sender_account = Account::new(
+        sender_account_id,
+        AssetVault::new(&[fungible_asset_1, fungible_asset_2]).unwrap(),
+        sender_account.storage().clone(),
+        sender_account.code().clone(),
+        sender_account.nonce(),
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset_1}
+            call.wallet::send_asset
+            # => [note_ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+
+            push.{asset_2}
+            call.wallet::move_asset_to_note
+            # => [note_ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+
+            dropw dropw dropw drop drop
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            note_type = NoteType::OffChain as u8,
+            tag = tag,
+            asset_1 = prepare_word(&fungible_asset_1.into()),
+            asset_2 = prepare_word(&fungible_asset_2.into()),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args: TransactionArgs = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    // the created note must contain both assets
+    let assets = NoteAssets::new(vec![fungible_asset_1, fungible_asset_2]).unwrap();
+    let expected_note_id = NoteId::new(recipient.into(), assets.commitment());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), expected_note_id);
+    let vault_delta = executed_transaction.account_delta().vault();
+    assert_eq!(vault_delta.added_assets().count(), 0);
+    assert_eq!(vault_delta.removed_assets().count(), 2);
+
+    assert!(prove_and_verify_transaction(executed_transaction).is_ok());
+}
+
+#[test]
+/// Testing the basic Miden wallet - receiving an asset with the signature provided by the
+/// transaction authenticator during execution
+fn prove_receive_asset_via_wallet_with_authenticator() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([3_u8; 32]));
+    let target_account =
+        get_account_with_default_account_code(target_account_id, sec_key.public_key().into(), None);
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, receive_asset_script());
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    // the secret key is not in the advice map, and thus the transaction cannot be executed
+    // without an authenticator
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code.clone(), vec![], vec![]).unwrap();
+    assert!(executor
+        .execute_transaction(
+            target_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script)
+        )
+        .is_err());
+
+    // the authenticator does not hold the secret key, and thus the transaction fails with the
+    // error returned by the authenticator
+    let other_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([4_u8; 32]));
+    let authenticator = BasicAuthenticator::new(&[other_key], RpoRandomCoin::new([ZERO; 4]));
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(Arc::new(authenticator));
+    executor.load_account(target_account.id()).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code.clone(), vec![], vec![]).unwrap();
+    let error = executor
+        .execute_transaction(
+            target_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error,
+        TransactionExecutorError::SignatureGenerationFailed(AuthenticationError::UnknownKey(
+            Word::from(sec_key.public_key()).into()
+        ))
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let authenticator = BasicAuthenticator::new(&[sec_key], RpoRandomCoin::new([ZERO; 4]));
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_authenticator(Arc::new(authenticator));
+    executor.load_account(target_account.id()).unwrap();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let executed_transaction = executor
+        .execute_transaction(
+            target_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap();
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&[fungible_asset_1.into()]).unwrap().commitment()
+    );
+
+    // the signature is included into the advice witness, and thus the transaction can be proven
+    // without the authenticator
+    assert!(prove_and_verify_transaction(executed_transaction).is_ok());
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
@@ -219,3 +518,303 @@ fn wallet_creation() {
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
 }
+
+#[test]
+/// Testing the basic Miden wallet - receiving an asset with an account using no authentication
+fn receive_asset_via_wallet_with_no_auth() {
+    use miden_objects::accounts::{AccountStorageType, AccountType};
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let (wallet, _) = create_basic_wallet(
+        [3_u8; 32],
+        AuthScheme::NoAuth,
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    assert_eq!(wallet.storage().get_item(0).as_elements(), &[ZERO; 4]);
+
+    let target_account = Account::new(
+        wallet.id(),
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, receive_asset_script());
+
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_no_auth
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let simulated_transaction = executor
+        .simulate_transaction(target_account.id(), block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    let executed_transaction = executor
+        .execute_transaction(target_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&[fungible_asset_1.into()]).unwrap().commitment()
+    );
+
+    // simulating the transaction does not require any signatures
+    assert_eq!(simulated_transaction.account_delta(), executed_transaction.account_delta());
+}
+
+#[test]
+#[ignore]
+/// Testing the basic Miden wallet - receiving an asset with an account using ECDSA secp256k1
+/// authentication
+///
+/// Verifying an ECDSA signature in the VM takes more than 8 million cycles, and executing this
+/// test requires more memory than is available on CI machines, so it is ignored by default. The
+/// tests in `miden-lib` cover the conversion of the message and the loading of the public key by
+/// the `auth_tx_ecdsa_secp256k1` procedure, but the verification of the signature in the VM is
+/// only covered by ignored tests.
+fn receive_asset_via_wallet_with_ecdsa_secp256k1() {
+    use miden_lib::auth::{
+        ecdsa_secp256k1::{signature_advice_entry, SecretKey},
+        tx_auth_message,
+    };
+    use miden_objects::{
+        accounts::{AccountStorageType, AccountType},
+        Digest,
+    };
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let secret_key = SecretKey::from_bytes(&[7_u8; 32]).unwrap();
+    let pub_key = secret_key.public_key();
+
+    let (wallet, _) = create_basic_wallet(
+        [4_u8; 32],
+        AuthScheme::EcdsaSecp256k1 { pub_key },
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    let pub_key_word: Word = pub_key.into();
+    assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
+
+    let target_account = Account::new(
+        wallet.id(),
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, receive_asset_script());
+
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let input_notes_hash = InputNotes::new(data_store.notes.clone()).unwrap().commitment();
+
+    // sign the transaction message; the transaction does not create any notes
+    let message = tx_auth_message(target_account.id(), ONE, input_notes_hash, Digest::default());
+    let signature = secret_key.sign(message);
+    let (key, values) = signature_advice_entry(&pub_key, message, &signature);
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::auth::ecdsa_secp256k1->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_ecdsa_secp256k1
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(key.into(), values)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
+#[test]
+/// Testing the multisig Miden wallet - receiving an asset with a 2-of-3 multisig account
+fn receive_asset_via_multisig_wallet() {
+    use miden_lib::accounts::wallets::{create_multisig_wallet, multisig_approvals_advice};
+    use miden_objects::accounts::{AccountStorageType, AccountType};
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+
+    let approvers: Vec<SecretKey> = (0..3_u8)
+        .map(|i| SecretKey::with_rng(&mut ChaCha20Rng::from_seed([i; 32])))
+        .collect();
+    let approver_pub_keys: Vec<_> = approvers.iter().map(|key| key.public_key()).collect();
+
+    let (wallet, _) = create_multisig_wallet(
+        [5_u8; 32],
+        &approver_pub_keys,
+        2,
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    assert_eq!(
+        wallet.storage().get_item(0).as_elements(),
+        &[Felt::new(2), Felt::new(3), ZERO, ZERO]
+    );
+
+    let target_account = Account::new(
+        wallet.id(),
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, receive_asset_script());
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::auth::multisig->auth_tx
+
+        begin
+            call.auth_tx::auth_tx_rpo_falcon512_multisig
+        end
+        ",
+    )
+    .unwrap();
+
+    // executes the transaction signed by the approvers with the specified flags
+    let execute = |approvals: &[bool]| {
+        let mut executor = TransactionExecutor::new(data_store.clone());
+        executor.load_account(target_account.id()).unwrap();
+
+        let mut advice = vec![multisig_approvals_advice(&target_account, approvals)];
+        for (approver, _) in approvers.iter().zip(approvals).filter(|(_, &approved)| approved) {
+            advice.push(get_key_pair_advice(approver));
+        }
+
+        let tx_script = executor.compile_tx_script(tx_script_code.clone(), advice, vec![]).unwrap();
+        executor.execute_transaction(
+            target_account.id(),
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+    };
+
+    // signatures of two approvers satisfy the threshold
+    let executed_transaction = execute(&[true, false, true]).unwrap();
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.final_account().vault_root(),
+        AssetVault::new(&[fungible_asset_1.into()]).unwrap().commitment()
+    );
+
+    // a signature of a single approver does not satisfy the threshold
+    assert!(execute(&[false, true, false]).is_err());
+
+    // the keys of the approvers are not replaced during a simulation, and thus simulating the
+    // transaction requires the signatures of the approvers
+    let approvals = [true, false, true];
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+    let advice = vec![multisig_approvals_advice(&target_account, &approvals)];
+    let tx_script = executor.compile_tx_script(tx_script_code.clone(), advice, vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    assert!(executor
+        .simulate_transaction(target_account.id(), block_ref, &note_ids, tx_args.clone())
+        .is_err());
+
+    let authenticator = BasicAuthenticator::new(
+        &[approvers[0].clone(), approvers[2].clone()],
+        RpoRandomCoin::new([ZERO; 4]),
+    );
+    let executor = executor.with_authenticator(Arc::new(authenticator));
+    let simulated_transaction = executor
+        .simulate_transaction(target_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+    assert_eq!(simulated_transaction.account_delta(), executed_transaction.account_delta());
+
+    // invalid multisig configurations are rejected
+    for (approvers, threshold) in [
+        (vec![], 1),
+        (approver_pub_keys.clone(), 0),
+        (approver_pub_keys.clone(), 4),
+        (vec![approver_pub_keys[0], approver_pub_keys[0]], 1),
+    ] {
+        assert!(create_multisig_wallet(
+            [5_u8; 32],
+            &approvers,
+            threshold,
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageType::OffChain,
+        )
+        .is_err());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn receive_asset_script() -> ProgramAst {
+    ProgramAst::parse(
+        "
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    # add the asset
+    begin
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+    end
+    ",
+    )
+    .unwrap()
+}
+
+fn get_key_pair_advice(sec_key: &SecretKey) -> (Word, Vec<Felt>) {
+    let pub_key: Word = sec_key.public_key().into();
+    let mut pk_sk_bytes = sec_key.to_bytes();
+    pk_sk_bytes.append(&mut pub_key.to_bytes());
+
+    (pub_key, pk_sk_bytes.iter().map(|a| Felt::new(*a as u64)).collect())
+}
@@ -1,8 +1,10 @@
+use alloc::collections::BTreeMap;
+
 use miden_lib::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 use miden_objects::{
     accounts::{
         get_account_seed_single, Account, AccountCode, AccountId, AccountStorage,
-        AccountStorageType, AccountType, SlotItem, StorageSlot,
+        AccountStorageType, AccountType, SlotItem, StorageArray, StorageMap, StorageSlot,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
@@ -10,8 +12,7 @@ use miden_objects::{
     },
     assembly::{Assembler, ModuleAst},
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::merkle::Smt,
-    Felt, FieldElement, Word, ZERO,
+    Digest, Felt, FieldElement, Word, ZERO,
 };
 
 use crate::{
@@ -29,6 +30,17 @@ pub const STORAGE_INDEX_0: u8 = 20;
 pub const STORAGE_VALUE_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
 pub const STORAGE_INDEX_1: u8 = 30;
 pub const STORAGE_VALUE_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+pub const STORAGE_INDEX_2: u8 = 40;
+pub const STORAGE_LEAVES_2: [(Digest, Word); 2] = [
+    (
+        Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+        [Felt::new(1_u64), Felt::new(2_u64), Felt::new(3_u64), Felt::new(4_u64)],
+    ),
+    (
+        Digest::new([Felt::new(105), Felt::new(106), Felt::new(107), Felt::new(108)]),
+        [Felt::new(5_u64), Felt::new(6_u64), Felt::new(7_u64), Felt::new(8_u64)],
+    ),
+];
 
 pub fn storage_item_0() -> SlotItem {
     SlotItem {
@@ -44,6 +56,35 @@ pub fn storage_item_1() -> SlotItem {
     }
 }
 
+pub const STORAGE_INDEX_3: u8 = 50;
+pub const STORAGE_ARRAY_DEPTH_3: u8 = 4;
+pub const STORAGE_ELEMENTS_3: [(u64, Word); 2] = [
+    (3, [Felt::new(11_u64), Felt::new(12_u64), Felt::new(13_u64), Felt::new(14_u64)]),
+    (9, [Felt::new(15_u64), Felt::new(16_u64), Felt::new(17_u64), Felt::new(18_u64)]),
+];
+
+pub fn storage_map_2() -> StorageMap {
+    StorageMap::with_entries(STORAGE_LEAVES_2).unwrap()
+}
+
+pub fn storage_item_2() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_2,
+        slot: StorageSlot::new_map(*storage_map_2().root()),
+    }
+}
+
+pub fn storage_array_3() -> StorageArray {
+    StorageArray::with_elements(STORAGE_ARRAY_DEPTH_3, STORAGE_ELEMENTS_3).unwrap()
+}
+
+pub fn storage_item_3() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_3,
+        slot: StorageSlot::new_array(*storage_array_3().root(), STORAGE_ARRAY_DEPTH_3),
+    }
+}
+
 /// Creates an [AssetVault] with 4 assets.
 ///
 /// The ids of the assets added to the vault are defined by the following constants:
@@ -73,12 +114,20 @@ fn mock_account_vault() -> AssetVault {
 
 pub fn mock_account_storage() -> AccountStorage {
     // create account storage
-    AccountStorage::new(vec![storage_item_0(), storage_item_1()]).unwrap()
+    AccountStorage::new(vec![
+        storage_item_0(),
+        storage_item_1(),
+        storage_item_2(),
+        storage_item_3(),
+    ])
+    .and_then(|storage| storage.with_maps(BTreeMap::from([(STORAGE_INDEX_2, storage_map_2())])))
+    .and_then(|storage| storage.with_arrays(BTreeMap::from([(STORAGE_INDEX_3, storage_array_3())])))
+    .unwrap()
 }
 
 // The MAST root of the default account's interface. Use these constants to interact with the
 // account's procedures.
-const MASTS: [&str; 8] = [
+const MASTS: [&str; 10] = [
     "0xe06a83054c72efc7e32698c4fc6037620cde834c9841afb038a5d39889e502b6",
     "0xd0260c15a64e796833eb2987d4072ac2ea824b3ce4a54a1e693bada6e82f71dd",
     "0xd765111e22479256e87a57eaf3a27479d19cc876c9a715ee6c262e0a0d47a2ac",
@@ -87,6 +136,8 @@ const MASTS: [&str; 8] = [
     "0xef07641ea1aa8fe85d8f854d29bf729b92251e1433244892138fd9ca898a5a22",
     "0xff06b90f849c4b262cbfbea67042c4ea017ea0e9c558848a951d44b23370bec5",
     "0x8ef0092134469a1330e3c468f57c7f085ce611645d09cc7516c786fefc71d794",
+    "0x796370c4bcc133ab68768875fd375c10294ec4dec4241269e0149e5d545a95a6",
+    "0xdeaa35665307abe14aa9c8af974666d896e7a4e864093003826e057d1bdebef7",
 ];
 pub const ACCOUNT_RECEIVE_ASSET_MAST_ROOT: &str = MASTS[0];
 pub const ACCOUNT_SEND_ASSET_MAST_ROOT: &str = MASTS[1];
@@ -96,6 +147,8 @@ pub const ACCOUNT_SET_CODE_MAST_ROOT: &str = MASTS[4];
 pub const ACCOUNT_CREATE_NOTE_MAST_ROOT: &str = MASTS[5];
 pub const ACCOUNT_ACCOUNT_PROCEDURE_1_MAST_ROOT: &str = MASTS[6];
 pub const ACCOUNT_ACCOUNT_PROCEDURE_2_MAST_ROOT: &str = MASTS[7];
+pub const ACCOUNT_SET_MAP_ITEM_MAST_ROOT: &str = MASTS[8];
+pub const ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT: &str = MASTS[9];
 
 // ACCOUNT ASSEMBLY CODE
 // ================================================================================================
@@ -106,6 +159,7 @@ pub const DEFAULT_ACCOUNT_CODE: &str = "
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
     export.basic_eoa::auth_tx_rpo_falcon512
 ";
 
@@ -172,6 +226,24 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
                 push.2.1
                 sub
             end
+
+            # acct proc 8
+            export.set_map_item
+                exec.account::set_map_item
+                # => [OLD_MAP_ROOT, OLD_VALUE, 0]
+
+                movup.8 drop
+                # => [OLD_MAP_ROOT, OLD_VALUE]
+            end
+
+            # acct proc 9
+            export.set_array_item
+                exec.account::set_array_item
+                # => [OLD_ARRAY_ROOT, OLD_VALUE, 0, 0]
+
+                movup.8 drop movup.8 drop
+                # => [OLD_ARRAY_ROOT, OLD_VALUE]
+            end
             ";
     let account_module_ast = ModuleAst::parse(account_code).unwrap();
     let code = AccountCode::new(account_module_ast, assembler).unwrap();
@@ -193,8 +265,10 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
         code.procedures()[5].to_hex(),
         code.procedures()[6].to_hex(),
         code.procedures()[7].to_hex(),
+        code.procedures()[8].to_hex(),
+        code.procedures()[9].to_hex(),
     ];
-    assert!(current == MASTS, "const MASTS: [&str; 8] = {:?};", current);
+    assert!(current == MASTS, "const MASTS: [&str; 10] = {:?};", current);
 
     code
 }
@@ -272,14 +346,13 @@ pub fn mock_non_fungible_faucet(
     };
 
     // construct nft tree
-    let nft_tree = Smt::with_entries(entires).unwrap();
-
-    // TODO: add nft tree data to account storage?
+    let nft_tree = StorageMap::with_entries(entires).unwrap();
 
     let account_storage = AccountStorage::new(vec![SlotItem {
         index: FAUCET_STORAGE_DATA_SLOT,
         slot: StorageSlot::new_map(*nft_tree.root()),
     }])
+    .and_then(|storage| storage.with_maps(BTreeMap::from([(FAUCET_STORAGE_DATA_SLOT, nft_tree)])))
     .unwrap();
     let account_id = AccountId::try_from(account_id).unwrap();
     let account_code = mock_account_code(assembler);
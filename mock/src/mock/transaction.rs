@@ -155,6 +155,7 @@ pub fn mock_executed_tx(asset_preservation: AssetPreservationStatus) -> Executed
     let tx_outputs = TransactionOutputs {
         account: final_account.into(),
         output_notes: OutputNotes::new(output_notes).unwrap(),
+        expiration_block_num: u32::MAX,
     };
 
     let program = build_dummy_tx_program();
@@ -89,6 +89,8 @@ impl Deserializable for AccountData {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum AuthData {
     RpoFalcon512Seed([u8; 32]),
+    EcdsaSecp256k1SecretKey([u8; 32]),
+    NoAuth,
 }
 
 // SERIALIZATION
@@ -101,6 +103,13 @@ impl Serializable for AuthData {
                 0_u8.write_into(target);
                 seed.write_into(target);
             },
+            AuthData::EcdsaSecp256k1SecretKey(secret_key) => {
+                1_u8.write_into(target);
+                secret_key.write_into(target);
+            },
+            AuthData::NoAuth => {
+                2_u8.write_into(target);
+            },
         }
     }
 }
@@ -113,6 +122,11 @@ impl Deserializable for AuthData {
                 let seed = <[u8; 32]>::read_from(source)?;
                 Ok(AuthData::RpoFalcon512Seed(seed))
             },
+            1 => {
+                let secret_key = <[u8; 32]>::read_from(source)?;
+                Ok(AuthData::EcdsaSecp256k1SecretKey(secret_key))
+            },
+            2 => Ok(AuthData::NoAuth),
             value => Err(DeserializationError::InvalidValue(format!("Invalid value: {}", value))),
         }
     }
@@ -187,6 +201,23 @@ mod tests {
         assert_eq!(account_data, account_data_2);
     }
 
+    #[test]
+    fn auth_data_correctly_serialises_and_deserialises() {
+        let auth_data = [
+            AuthData::RpoFalcon512Seed([1u8; 32]),
+            AuthData::EcdsaSecp256k1SecretKey([2u8; 32]),
+            AuthData::NoAuth,
+        ];
+
+        for (tag, auth) in auth_data.into_iter().enumerate() {
+            let bytes = auth.to_bytes();
+            assert_eq!(bytes[0], tag as u8);
+            assert_eq!(auth, AuthData::read_from_bytes(&bytes).unwrap());
+        }
+
+        assert!(AuthData::read_from_bytes(&[3u8]).is_err());
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn account_data_is_correctly_writen_and_read_to_and_from_file() {
@@ -1,12 +1,13 @@
 use alloc::string::ToString;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable, Word, ZERO,
+    Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
+    Word, ZERO,
 };
-use crate::{assets::Asset, AccountDeltaError};
+use crate::{assets::Asset, AccountDeltaError, AccountError, ONE};
 
 mod storage;
-pub use storage::AccountStorageDelta;
+pub use storage::{AccountStorageDelta, StorageArrayDelta, StorageMapDelta};
 
 mod vault;
 pub use vault::AccountVaultDelta;
@@ -34,10 +35,13 @@ impl AccountDelta {
     // --------------------------------------------------------------------------------------------
     /// Returns new [AccountDelta] instantiated from the provided components.
     ///
+    /// A delta may update only the nonce of an account, e.g., when the updates made by several
+    /// transactions against the account cancel each other out.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - Storage or vault deltas are invalid.
-    /// - Storage and vault deltas are empty, and the nonce was updated.
+    /// - The nonce was set to 0.
     /// - Storage or vault deltas are not empty, but nonce was not updated.
     pub fn new(
         storage: AccountStorageDelta,
@@ -57,7 +61,7 @@ impl AccountDelta {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns true if this account delta does not contain any updates.
+    /// Returns true if this account delta does not contain any storage or vault updates.
     pub fn is_empty(&self) -> bool {
         self.storage.is_empty() && self.vault.is_empty()
     }
@@ -81,6 +85,57 @@ impl AccountDelta {
     pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>) {
         (self.storage, self.vault, self.nonce)
     }
+
+    // DELTA OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an account delta which combines the updates of this delta with the updates of the
+    /// `other` delta, assuming `other` is applied after this delta.
+    ///
+    /// Storage and vault deltas are merged as described in [AccountStorageDelta::merge()] and
+    /// [AccountVaultDelta::merge()], and the greater of the two nonces is kept.
+    ///
+    /// If the storage and vault updates of the two deltas cancel each other out, the returned
+    /// delta updates only the nonce.
+    ///
+    /// # Errors
+    /// Returns an error if storage or vault deltas cannot be merged.
+    pub fn merge(&self, other: &Self) -> Result<Self, AccountDeltaError> {
+        let storage = self.storage.merge(&other.storage)?;
+        let vault = self.vault.merge(&other.vault)?;
+        let nonce = match (self.nonce, other.nonce) {
+            (Some(nonce), Some(other_nonce)) => Some(if nonce.as_int() > other_nonce.as_int() {
+                nonce
+            } else {
+                other_nonce
+            }),
+            (nonce, other_nonce) => nonce.or(other_nonce),
+        };
+
+        Self::new(storage, vault, nonce)
+    }
+
+    /// Returns an account delta which reverts the storage and vault updates of this delta, given
+    /// the `initial_state` of the account this delta is applied to.
+    ///
+    /// Rolling back a delta does not roll back the nonce: since the nonce of an account can only
+    /// increase, the returned delta bumps the nonce of this delta by one instead of restoring the
+    /// initial nonce. Thus, applying this delta and then the returned delta leaves the account
+    /// with the initial storage and vault, but with a nonce greater than the nonce set by this
+    /// delta.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The storage of the provided account does not contain the maps or arrays updated by this
+    ///   delta.
+    /// - The returned delta is invalid.
+    pub fn invert(&self, initial_state: &Account) -> Result<Self, AccountError> {
+        let storage = self.storage.invert(initial_state.storage())?;
+        let vault = self.vault.invert();
+        let nonce = self.nonce.map(|nonce| nonce + ONE);
+
+        Self::new(storage, vault, nonce).map_err(AccountError::InvalidAccountDelta)
+    }
 }
 
 impl Serializable for AccountDelta {
@@ -111,35 +166,24 @@ impl Deserializable for AccountDelta {
 ///
 /// # Errors
 /// Returns an error if:
-/// - Storage or vault were updated, but the nonce was either not updated or set to 0.
-/// - Storage and vault were not updated, but the nonce was updated.
+/// - The nonce was set to 0.
+/// - Storage or vault were updated, but the nonce was not updated.
 fn validate_nonce(
     nonce: Option<Felt>,
     storage: &AccountStorageDelta,
     vault: &AccountVaultDelta,
 ) -> Result<(), AccountDeltaError> {
-    if !storage.is_empty() || !vault.is_empty() {
-        match nonce {
-            Some(nonce) => {
-                if nonce == ZERO {
-                    return Err(AccountDeltaError::InconsistentNonceUpdate(
-                        "zero nonce for a non-empty account delta".to_string(),
-                    ));
-                }
-            },
-            None => {
-                return Err(AccountDeltaError::InconsistentNonceUpdate(
-                    "nonce not updated for non-empty account delta".to_string(),
-                ))
-            },
-        }
-    } else if nonce.is_some() {
-        return Err(AccountDeltaError::InconsistentNonceUpdate(
-            "nonce updated for empty delta".to_string(),
-        ));
+    match nonce {
+        Some(nonce) if nonce == ZERO => Err(AccountDeltaError::InconsistentNonceUpdate(
+            "zero nonce for an account delta".to_string(),
+        )),
+        None if !storage.is_empty() || !vault.is_empty() => {
+            Err(AccountDeltaError::InconsistentNonceUpdate(
+                "nonce not updated for non-empty account delta".to_string(),
+            ))
+        },
+        _ => Ok(()),
     }
-
-    Ok(())
 }
 
 // TESTS
@@ -147,8 +191,31 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
     use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
-    use crate::{ONE, ZERO};
+    use crate::{
+        accounts::{
+            Account, AccountCode, AccountId, AccountStorage, SlotItem, StorageArray,
+            StorageArrayDelta, StorageMap, StorageMapDelta, StorageSlot,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        },
+        assembly::{Assembler, ModuleAst},
+        assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        AccountDeltaError, Felt, Word, ONE, ZERO,
+    };
+
+    const NUM_VALUE_SLOTS: u8 = 4;
+    const MAP_SLOT: u8 = 4;
+    const ARRAY_SLOT: u8 = 5;
+    const ARRAY_DEPTH: u8 = 3;
+    const NUM_MAP_KEYS: u64 = 4;
+    const NUM_NON_FUNGIBLE_ASSETS: u8 = 4;
+    const NUM_ITERATIONS: u64 = 64;
 
     #[test]
     fn account_delta_nonce_validation() {
@@ -156,24 +223,285 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
 
-        let vault_delta = AccountVaultDelta {
-            added_assets: vec![],
-            removed_assets: vec![],
-        };
+        let vault_delta = AccountVaultDelta::default();
 
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_ok());
-        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_err());
+        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ZERO)).is_err());
+        assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
 
         // non-empty delta
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![1],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
 
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_err());
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ZERO)).is_err());
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
     }
+
+    #[test]
+    fn merged_delta_is_equivalent_to_sequential_deltas() {
+        for seed in 0..NUM_ITERATIONS {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            let initial_account = build_account(&mut rng);
+            let delta_1 = random_delta(&mut rng, &initial_account);
+            let mut account_1 = initial_account.clone();
+            account_1.apply_delta(&delta_1).unwrap();
+
+            let delta_2 = random_delta(&mut rng, &account_1);
+            let mut account_2 = account_1.clone();
+            account_2.apply_delta(&delta_2).unwrap();
+
+            let merged_delta = delta_1.merge(&delta_2).unwrap();
+            let mut account = initial_account.clone();
+            account.apply_delta(&merged_delta).unwrap();
+            assert_eq!(account, account_2, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn merge_of_canceling_deltas_updates_only_nonce() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let mut vault_1 = AccountVaultDelta::default();
+        vault_1.add_asset(asset).unwrap();
+        let delta_1 =
+            AccountDelta::new(AccountStorageDelta::default(), vault_1, Some(Felt::new(3))).unwrap();
+
+        let mut vault_2 = AccountVaultDelta::default();
+        vault_2.remove_asset(asset).unwrap();
+        let delta_2 =
+            AccountDelta::new(AccountStorageDelta::default(), vault_2, Some(Felt::new(2))).unwrap();
+
+        let merged_delta = delta_1.merge(&delta_2).unwrap();
+        assert!(merged_delta.is_empty());
+        assert_eq!(merged_delta.nonce(), Some(Felt::new(3)));
+    }
+
+    #[test]
+    fn inverted_delta_reverts_delta() {
+        for seed in 0..NUM_ITERATIONS {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            let initial_account = build_account(&mut rng);
+            let delta = random_delta(&mut rng, &initial_account);
+            let mut account = initial_account.clone();
+            account.apply_delta(&delta).unwrap();
+
+            let inverse = delta.invert(&initial_account).unwrap();
+            let (storage, vault, nonce) = inverse.clone().into_parts();
+            assert_eq!(AccountDelta::new(storage, vault, nonce), Ok(inverse.clone()));
+
+            // rolling back restores the storage and the vault, but bumps the nonce
+            account.apply_delta(&inverse).unwrap();
+            assert_eq!(account.vault(), initial_account.vault(), "seed {seed}");
+            assert_eq!(account.storage(), initial_account.storage(), "seed {seed}");
+            assert_eq!(inverse.nonce(), Some(delta.nonce().unwrap() + ONE), "seed {seed}");
+            assert_eq!(account.nonce(), delta.nonce().unwrap() + ONE, "seed {seed}");
+            assert!(account.nonce().as_int() > initial_account.nonce().as_int(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn account_vault_delta_merge() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible = |amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+        let non_fungible = non_fungible_asset(0);
+
+        // fungible amounts are folded and non-fungible assets added and removed cancel out
+        let mut delta_1 = AccountVaultDelta::default();
+        delta_1.add_asset(fungible(100)).unwrap();
+        delta_1.add_asset(non_fungible).unwrap();
+
+        let mut delta_2 = AccountVaultDelta::default();
+        delta_2.remove_asset(fungible(30)).unwrap();
+        delta_2.remove_asset(non_fungible).unwrap();
+
+        let expected = AccountVaultDelta {
+            fungible_assets: BTreeMap::from([(faucet_id, 70)]),
+            ..Default::default()
+        };
+        assert_eq!(delta_1.merge(&delta_2), Ok(expected));
+        assert!(delta_2.merge(&delta_1).unwrap().removed_non_fungible_assets.is_empty());
+
+        // the same non-fungible asset can not be added twice
+        assert_eq!(
+            delta_1.merge(&delta_1),
+            Err(AccountDeltaError::DuplicateVaultUpdate(non_fungible))
+        );
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds an account with random values in its value slots, map and array, and a random
+    /// selection of fungible and non-fungible assets.
+    fn build_account(rng: &mut StdRng) -> Account {
+        let source = "
+            export.foo
+                push.1 push.2 mul
+            end
+        ";
+        let module = ModuleAst::parse(source).unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+
+        let mut map_entries = Vec::new();
+        for key in 0..NUM_MAP_KEYS {
+            if rng.gen_bool(0.5) {
+                map_entries.push((map_key(key).into(), random_word(rng)));
+            }
+        }
+        let map = StorageMap::with_entries(map_entries).unwrap();
+
+        let mut array_elements = Vec::new();
+        for index in 0..1 << ARRAY_DEPTH {
+            if rng.gen_bool(0.5) {
+                array_elements.push((index, random_word(rng)));
+            }
+        }
+        let array = StorageArray::with_elements(ARRAY_DEPTH, array_elements).unwrap();
+
+        let mut slot_items: Vec<SlotItem> = (0..NUM_VALUE_SLOTS)
+            .map(|index| SlotItem {
+                index,
+                slot: StorageSlot::new_value(random_word(rng)),
+            })
+            .collect();
+        slot_items.push(SlotItem {
+            index: MAP_SLOT,
+            slot: StorageSlot::new_map(map.root().into()),
+        });
+        slot_items.push(SlotItem {
+            index: ARRAY_SLOT,
+            slot: StorageSlot::new_array(array.root().into(), ARRAY_DEPTH),
+        });
+        let storage = AccountStorage::new(slot_items)
+            .and_then(|storage| storage.with_maps(BTreeMap::from([(MAP_SLOT, map)])))
+            .and_then(|storage| storage.with_arrays(BTreeMap::from([(ARRAY_SLOT, array)])))
+            .unwrap();
+
+        let mut assets: Vec<Asset> = Vec::new();
+        for faucet_id in fungible_faucet_ids() {
+            if rng.gen_bool(0.5) {
+                let amount = rng.gen_range(1..1000);
+                assets.push(FungibleAsset::new(faucet_id, amount).unwrap().into());
+            }
+        }
+        for data in 0..NUM_NON_FUNGIBLE_ASSETS {
+            if rng.gen_bool(0.5) {
+                assets.push(non_fungible_asset(data));
+            }
+        }
+        let vault = AssetVault::new(&assets).unwrap();
+
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        Account::new(id, vault, storage, code, Felt::new(rng.gen_range(1..10)))
+    }
+
+    /// Returns a random delta which can be applied to the provided account.
+    fn random_delta(rng: &mut StdRng, account: &Account) -> AccountDelta {
+        let mut vault_delta = AccountVaultDelta::default();
+        for faucet_id in fungible_faucet_ids() {
+            let balance = account.vault().get_balance(faucet_id).unwrap();
+            match rng.gen_range(0..3) {
+                0 => {
+                    let amount = rng.gen_range(1..1000);
+                    vault_delta
+                        .add_asset(FungibleAsset::new(faucet_id, amount).unwrap().into())
+                        .unwrap();
+                },
+                1 if balance > 0 => {
+                    let amount = rng.gen_range(1..=balance);
+                    vault_delta
+                        .remove_asset(FungibleAsset::new(faucet_id, amount).unwrap().into())
+                        .unwrap();
+                },
+                _ => (),
+            }
+        }
+        for asset in (0..NUM_NON_FUNGIBLE_ASSETS).map(non_fungible_asset) {
+            if rng.gen_bool(0.5) {
+                if account.vault().has_non_fungible_asset(asset).unwrap() {
+                    vault_delta.remove_asset(asset).unwrap();
+                } else {
+                    vault_delta.add_asset(asset).unwrap();
+                }
+            }
+        }
+
+        let mut storage_delta = AccountStorageDelta::default();
+        for index in 0..NUM_VALUE_SLOTS {
+            match rng.gen_range(0..3) {
+                0 => storage_delta.cleared_items.push(index),
+                1 => storage_delta.updated_items.push((index, random_word(rng))),
+                _ => (),
+            }
+        }
+
+        let mut map_delta = StorageMapDelta::default();
+        for key in (0..NUM_MAP_KEYS).map(map_key) {
+            match rng.gen_range(0..3) {
+                0 => map_delta.cleared_leaves.push(key),
+                1 => map_delta.updated_leaves.push((key, random_word(rng))),
+                _ => (),
+            }
+        }
+        if !map_delta.is_empty() {
+            storage_delta.updated_maps.push((MAP_SLOT, map_delta));
+        }
+
+        let mut array_delta = StorageArrayDelta::default();
+        for index in 0..1 << ARRAY_DEPTH {
+            match rng.gen_range(0..3) {
+                0 => array_delta.cleared_elements.push(index),
+                1 => array_delta.updated_elements.push((index, random_word(rng))),
+                _ => (),
+            }
+        }
+        if !array_delta.is_empty() {
+            storage_delta.updated_arrays.push((ARRAY_SLOT, array_delta));
+        }
+
+        // make sure the delta is not empty so that the nonce can be updated
+        if storage_delta.is_empty() && vault_delta.is_empty() {
+            storage_delta.updated_items.push((0, random_word(rng)));
+        }
+
+        let nonce = account.nonce() + Felt::new(rng.gen_range(1..3));
+        AccountDelta::new(storage_delta, vault_delta, Some(nonce)).unwrap()
+    }
+
+    fn fungible_faucet_ids() -> [AccountId; 2] {
+        [
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap(),
+        ]
+    }
+
+    fn non_fungible_asset(data: u8) -> Asset {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![data]).unwrap();
+        NonFungibleAsset::new(&details).unwrap().into()
+    }
+
+    fn map_key(key: u64) -> Word {
+        [Felt::new(key), ZERO, ZERO, ZERO]
+    }
+
+    /// Returns a random word which is empty with a small probability.
+    fn random_word(rng: &mut StdRng) -> Word {
+        if rng.gen_bool(0.1) {
+            return Word::default();
+        }
+        [Felt::new(rng.gen_range(0..100)), ONE, ZERO, ONE]
+    }
 }
@@ -1,9 +1,10 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use super::{
     AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     Word,
 };
+use crate::{accounts::AccountStorage, AccountError, EMPTY_WORD};
 
 // CONSTANTS
 // ================================================================================================
@@ -17,10 +18,16 @@ const MAX_MUTABLE_STORAGE_SLOT_IDX: u8 = 254;
 ///
 /// The differences are represented as follows:
 /// - item updates: represented by `cleared_items` and `updated_items` field.
+/// - map updates: represented by `updated_maps` field which contains a [StorageMapDelta] for each
+///   updated map slot.
+/// - array updates: represented by `updated_arrays` field which contains a [StorageArrayDelta] for
+///   each updated array slot.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     pub cleared_items: Vec<u8>,
     pub updated_items: Vec<(u8, Word)>,
+    pub updated_maps: Vec<(u8, StorageMapDelta)>,
+    pub updated_arrays: Vec<(u8, StorageArrayDelta)>,
 }
 
 impl AccountStorageDelta {
@@ -31,9 +38,19 @@ impl AccountStorageDelta {
     /// - The number of cleared or updated items is greater than 255.
     /// - Any of cleared or updated items are at slot 255 (i.e., immutable slot).
     /// - Any of the cleared or updated items is referenced more than once (e.g., updated twice).
+    /// - The number of updated maps is greater than 255.
+    /// - Any of the updated maps is at slot 255, is referenced more than once, or is also
+    ///   referenced by cleared or updated items.
+    /// - Any of the map deltas is invalid.
+    /// - The number of updated arrays is greater than 255.
+    /// - Any of the updated arrays is at slot 255, is referenced more than once, or is also
+    ///   referenced by cleared or updated items or by updated maps.
+    /// - Any of the array deltas is invalid.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
         let num_cleared_items = self.cleared_items.len();
         let num_updated_items = self.updated_items.len();
+        let num_updated_maps = self.updated_maps.len();
+        let num_updated_arrays = self.updated_arrays.len();
 
         if num_cleared_items > u8::MAX as usize {
             return Err(AccountDeltaError::TooManyClearedStorageItems {
@@ -45,6 +62,16 @@ impl AccountStorageDelta {
                 actual: num_updated_items,
                 max: u8::MAX as usize,
             });
+        } else if num_updated_maps > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageMaps {
+                actual: num_updated_maps,
+                max: u8::MAX as usize,
+            });
+        } else if num_updated_arrays > u8::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageArrays {
+                actual: num_updated_arrays,
+                max: u8::MAX as usize,
+            });
         }
 
         // make sure cleared items vector does not contain errors
@@ -73,12 +100,159 @@ impl AccountStorageDelta {
             }
         }
 
+        // make sure updated maps vector does not contain errors
+        for (pos, (idx, map_delta)) in self.updated_maps.iter().enumerate() {
+            if *idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(AccountDeltaError::ImmutableStorageSlot(*idx as usize));
+            }
+
+            if self.cleared_items.contains(idx)
+                || self.updated_items.iter().any(|x| x.0 == *idx)
+                || self.updated_maps[..pos].iter().any(|x| x.0 == *idx)
+            {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+
+            map_delta.validate()?;
+        }
+
+        // make sure updated arrays vector does not contain errors
+        for (pos, (idx, array_delta)) in self.updated_arrays.iter().enumerate() {
+            if *idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(AccountDeltaError::ImmutableStorageSlot(*idx as usize));
+            }
+
+            if self.cleared_items.contains(idx)
+                || self.updated_items.iter().any(|x| x.0 == *idx)
+                || self.updated_maps.iter().any(|x| x.0 == *idx)
+                || self.updated_arrays[..pos].iter().any(|x| x.0 == *idx)
+            {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+
+            array_delta.validate()?;
+        }
+
         Ok(())
     }
 
     /// Returns true if storage delta contains no updates.
     pub fn is_empty(&self) -> bool {
-        self.cleared_items.is_empty() && self.updated_items.is_empty()
+        self.cleared_items.is_empty()
+            && self.updated_items.is_empty()
+            && self.updated_maps.is_empty()
+            && self.updated_arrays.is_empty()
+    }
+
+    /// Returns a storage delta which combines the updates of this delta with the updates of the
+    /// `other` delta, assuming `other` is applied after this delta.
+    ///
+    /// For items, map leaves and array elements updated by both deltas, the update of the `other`
+    /// delta is kept.
+    ///
+    /// # Errors
+    /// Returns an error if the merged delta is invalid, e.g., if the same slot is updated as an
+    /// item by one delta and as a map or an array by the other delta.
+    pub fn merge(&self, other: &Self) -> Result<Self, AccountDeltaError> {
+        // cleared items are tracked as `None`
+        let mut items: BTreeMap<u8, Option<Word>> = BTreeMap::new();
+        let mut maps: BTreeMap<u8, StorageMapDelta> = BTreeMap::new();
+        let mut arrays: BTreeMap<u8, StorageArrayDelta> = BTreeMap::new();
+
+        for delta in [self, other] {
+            for &idx in delta.cleared_items.iter() {
+                items.insert(idx, None);
+            }
+
+            for &(idx, value) in delta.updated_items.iter() {
+                items.insert(idx, Some(value));
+            }
+
+            for (idx, map_delta) in delta.updated_maps.iter() {
+                let map_delta = match maps.remove(idx) {
+                    Some(prev_delta) => prev_delta.merge(map_delta),
+                    None => map_delta.clone(),
+                };
+                maps.insert(*idx, map_delta);
+            }
+
+            for (idx, array_delta) in delta.updated_arrays.iter() {
+                let array_delta = match arrays.remove(idx) {
+                    Some(prev_delta) => prev_delta.merge(array_delta),
+                    None => array_delta.clone(),
+                };
+                arrays.insert(*idx, array_delta);
+            }
+        }
+
+        let mut delta = Self {
+            updated_maps: maps.into_iter().collect(),
+            updated_arrays: arrays.into_iter().collect(),
+            ..Default::default()
+        };
+        for (idx, value) in items {
+            match value {
+                Some(value) => delta.updated_items.push((idx, value)),
+                None => delta.cleared_items.push(idx),
+            }
+        }
+        delta.validate()?;
+
+        Ok(delta)
+    }
+
+    /// Returns a storage delta which reverts the updates of this delta, given the `storage` this
+    /// delta is applied to.
+    ///
+    /// # Errors
+    /// Returns an error if any of the maps or arrays updated by this delta is not available in the
+    /// provided storage, or if an updated array element is out of bounds.
+    pub fn invert(&self, storage: &AccountStorage) -> Result<Self, AccountError> {
+        let mut inverse = Self::default();
+
+        let updated_items = self.updated_items.iter().map(|(idx, _)| idx);
+        for &idx in self.cleared_items.iter().chain(updated_items) {
+            let value: Word = storage.get_item(idx).into();
+            if value == EMPTY_WORD {
+                inverse.cleared_items.push(idx);
+            } else {
+                inverse.updated_items.push((idx, value));
+            }
+        }
+
+        for (idx, map_delta) in self.updated_maps.iter() {
+            let mut inverse_map_delta = StorageMapDelta::default();
+
+            let updated_leaves = map_delta.updated_leaves.iter().map(|(key, _)| key);
+            for &key in map_delta.cleared_leaves.iter().chain(updated_leaves) {
+                let value = storage.get_map_item(*idx, key)?;
+                if value == EMPTY_WORD {
+                    inverse_map_delta.cleared_leaves.push(key);
+                } else {
+                    inverse_map_delta.updated_leaves.push((key, value));
+                }
+            }
+
+            inverse.updated_maps.push((*idx, inverse_map_delta));
+        }
+
+        for (idx, array_delta) in self.updated_arrays.iter() {
+            let mut inverse_array_delta = StorageArrayDelta::default();
+
+            let updated_elements = array_delta.updated_elements.iter().map(|(index, _)| index);
+            for &index in array_delta.cleared_elements.iter().chain(updated_elements) {
+                let value = storage.get_array_item(*idx, index)?;
+                if value == EMPTY_WORD {
+                    inverse_array_delta.cleared_elements.push(index);
+                } else {
+                    inverse_array_delta.updated_elements.push((index, value));
+                }
+            }
+
+            inverse.updated_arrays.push((*idx, inverse_array_delta));
+        }
+
+        Ok(inverse)
     }
 }
 
@@ -96,6 +270,20 @@ impl Serializable for AccountStorageDelta {
             idx.write_into(target);
             value.write_into(target);
         }
+
+        assert!(self.updated_maps.len() <= u8::MAX as usize, "too many updated storage maps");
+        target.write_u8(self.updated_maps.len() as u8);
+        for (idx, map_delta) in self.updated_maps.iter() {
+            idx.write_into(target);
+            map_delta.write_into(target);
+        }
+
+        assert!(self.updated_arrays.len() <= u8::MAX as usize, "too many updated storage arrays");
+        target.write_u8(self.updated_arrays.len() as u8);
+        for (idx, array_delta) in self.updated_arrays.iter() {
+            idx.write_into(target);
+            array_delta.write_into(target);
+        }
     }
 }
 
@@ -155,7 +343,281 @@ impl Deserializable for AccountStorageDelta {
             updated_items.push((idx, value));
         }
 
-        Ok(Self { cleared_items, updated_items })
+        // deserialize and validate updated maps
+        let num_updated_maps = source.read_u8()? as usize;
+        let mut updated_maps: Vec<(u8, StorageMapDelta)> = Vec::with_capacity(num_updated_maps);
+        for _ in 0..num_updated_maps {
+            let idx = source.read_u8()?;
+            let map_delta = StorageMapDelta::read_from(source)?;
+
+            // make sure index is valid
+            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(DeserializationError::InvalidValue(
+                    "immutable storage map updated".to_string(),
+                ));
+            }
+
+            // make sure the same map hasn't been updated before
+            if updated_maps.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage map updated more than once".to_string(),
+                ));
+            }
+
+            // make sure the slot hasn't been cleared or updated as an item in the same delta
+            if cleared_items.contains(&idx) || updated_items.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage slot updated both as an item and as a map".to_string(),
+                ));
+            }
+
+            updated_maps.push((idx, map_delta));
+        }
+
+        // deserialize and validate updated arrays
+        let num_updated_arrays = source.read_u8()? as usize;
+        let mut updated_arrays: Vec<(u8, StorageArrayDelta)> =
+            Vec::with_capacity(num_updated_arrays);
+        for _ in 0..num_updated_arrays {
+            let idx = source.read_u8()?;
+            let array_delta = StorageArrayDelta::read_from(source)?;
+
+            // make sure index is valid
+            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(DeserializationError::InvalidValue(
+                    "immutable storage array updated".to_string(),
+                ));
+            }
+
+            // make sure the same array hasn't been updated before
+            if updated_arrays.iter().any(|x| x.0 == idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage array updated more than once".to_string(),
+                ));
+            }
+
+            // make sure the slot hasn't been updated as an item or as a map in the same delta
+            if cleared_items.contains(&idx)
+                || updated_items.iter().any(|x| x.0 == idx)
+                || updated_maps.iter().any(|x| x.0 == idx)
+            {
+                return Err(DeserializationError::InvalidValue(
+                    "storage slot updated both as an array and as an item or a map".to_string(),
+                ));
+            }
+
+            updated_arrays.push((idx, array_delta));
+        }
+
+        Ok(Self {
+            cleared_items,
+            updated_items,
+            updated_maps,
+            updated_arrays,
+        })
+    }
+}
+
+// STORAGE MAP DELTA
+// ================================================================================================
+
+/// [StorageMapDelta] stores the differences between two states of a storage map.
+///
+/// The differences are represented as follows:
+/// - leaf updates: represented by `cleared_leaves` and `updated_leaves` fields, where leaves are
+///   identified by their keys.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageMapDelta {
+    pub cleared_leaves: Vec<Word>,
+    pub updated_leaves: Vec<(Word, Word)>,
+}
+
+impl StorageMapDelta {
+    /// Checks whether this storage map delta is valid.
+    ///
+    /// # Errors
+    /// Returns an error if any of the cleared or updated leaves is referenced more than once
+    /// (e.g., updated twice).
+    pub fn validate(&self) -> Result<(), AccountDeltaError> {
+        // make sure cleared leaves vector does not contain errors
+        for (pos, key) in self.cleared_leaves.iter().enumerate() {
+            if self.cleared_leaves[..pos].contains(key) {
+                return Err(AccountDeltaError::DuplicateStorageMapLeaf(*key));
+            }
+        }
+
+        // make sure updated leaves vector does not contain errors
+        for (pos, (key, _)) in self.updated_leaves.iter().enumerate() {
+            if self.cleared_leaves.contains(key)
+                || self.updated_leaves[..pos].iter().any(|x| x.0 == *key)
+            {
+                return Err(AccountDeltaError::DuplicateStorageMapLeaf(*key));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if storage map delta contains no updates.
+    pub fn is_empty(&self) -> bool {
+        self.cleared_leaves.is_empty() && self.updated_leaves.is_empty()
+    }
+
+    /// Returns a storage map delta which combines the updates of this delta with the updates of
+    /// the `other` delta, assuming `other` is applied after this delta.
+    ///
+    /// For leaves updated by both deltas, the update of the `other` delta is kept.
+    pub fn merge(&self, other: &Self) -> Self {
+        let is_overridden = |key: &Word| {
+            other.cleared_leaves.contains(key) || other.updated_leaves.iter().any(|x| x.0 == *key)
+        };
+
+        let mut delta = self.clone();
+        delta.cleared_leaves.retain(|key| !is_overridden(key));
+        delta.updated_leaves.retain(|(key, _)| !is_overridden(key));
+        delta.cleared_leaves.extend_from_slice(&other.cleared_leaves);
+        delta.updated_leaves.extend_from_slice(&other.updated_leaves);
+
+        delta
+    }
+}
+
+impl Serializable for StorageMapDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.cleared_leaves.len() <= u32::MAX as usize, "too many cleared map leaves");
+        target.write_u32(self.cleared_leaves.len() as u32);
+        target.write_many(self.cleared_leaves.iter());
+
+        assert!(self.updated_leaves.len() <= u32::MAX as usize, "too many updated map leaves");
+        target.write_u32(self.updated_leaves.len() as u32);
+        for (key, value) in self.updated_leaves.iter() {
+            key.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageMapDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_cleared_leaves = source.read_u32()? as usize;
+        let cleared_leaves = source.read_many::<Word>(num_cleared_leaves)?;
+
+        let num_updated_leaves = source.read_u32()? as usize;
+        let mut updated_leaves = Vec::with_capacity(num_updated_leaves);
+        for _ in 0..num_updated_leaves {
+            let key = Word::read_from(source)?;
+            let value = Word::read_from(source)?;
+            updated_leaves.push((key, value));
+        }
+
+        let delta = Self { cleared_leaves, updated_leaves };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
+    }
+}
+
+// STORAGE ARRAY DELTA
+// ================================================================================================
+
+/// [StorageArrayDelta] stores the differences between two states of a storage array.
+///
+/// The differences are represented as follows:
+/// - element updates: represented by `cleared_elements` and `updated_elements` fields, where
+///   elements are identified by their indexes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageArrayDelta {
+    pub cleared_elements: Vec<u64>,
+    pub updated_elements: Vec<(u64, Word)>,
+}
+
+impl StorageArrayDelta {
+    /// Checks whether this storage array delta is valid.
+    ///
+    /// # Errors
+    /// Returns an error if any of the cleared or updated elements is referenced more than once
+    /// (e.g., updated twice).
+    pub fn validate(&self) -> Result<(), AccountDeltaError> {
+        // make sure cleared elements vector does not contain errors
+        for (pos, index) in self.cleared_elements.iter().enumerate() {
+            if self.cleared_elements[..pos].contains(index) {
+                return Err(AccountDeltaError::DuplicateStorageArrayElement(*index));
+            }
+        }
+
+        // make sure updated elements vector does not contain errors
+        for (pos, (index, _)) in self.updated_elements.iter().enumerate() {
+            if self.cleared_elements.contains(index)
+                || self.updated_elements[..pos].iter().any(|x| x.0 == *index)
+            {
+                return Err(AccountDeltaError::DuplicateStorageArrayElement(*index));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if storage array delta contains no updates.
+    pub fn is_empty(&self) -> bool {
+        self.cleared_elements.is_empty() && self.updated_elements.is_empty()
+    }
+
+    /// Returns a storage array delta which combines the updates of this delta with the updates of
+    /// the `other` delta, assuming `other` is applied after this delta.
+    ///
+    /// For elements updated by both deltas, the update of the `other` delta is kept.
+    pub fn merge(&self, other: &Self) -> Self {
+        let is_overridden = |index: &u64| {
+            other.cleared_elements.contains(index)
+                || other.updated_elements.iter().any(|x| x.0 == *index)
+        };
+
+        let mut delta = self.clone();
+        delta.cleared_elements.retain(|index| !is_overridden(index));
+        delta.updated_elements.retain(|(index, _)| !is_overridden(index));
+        delta.cleared_elements.extend_from_slice(&other.cleared_elements);
+        delta.updated_elements.extend_from_slice(&other.updated_elements);
+
+        delta
+    }
+}
+
+impl Serializable for StorageArrayDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.cleared_elements.len() <= u32::MAX as usize, "too many cleared elements");
+        target.write_u32(self.cleared_elements.len() as u32);
+        target.write_many(self.cleared_elements.iter());
+
+        assert!(self.updated_elements.len() <= u32::MAX as usize, "too many updated elements");
+        target.write_u32(self.updated_elements.len() as u32);
+        for (index, value) in self.updated_elements.iter() {
+            index.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageArrayDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_cleared_elements = source.read_u32()? as usize;
+        let cleared_elements = source.read_many::<u64>(num_cleared_elements)?;
+
+        let num_updated_elements = source.read_u32()? as usize;
+        let mut updated_elements = Vec::with_capacity(num_updated_elements);
+        for _ in 0..num_updated_elements {
+            let index = source.read_u64()?;
+            let value = Word::read_from(source)?;
+            updated_elements.push((index, value));
+        }
+
+        let delta = Self { cleared_elements, updated_elements };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
     }
 }
 
@@ -164,7 +626,9 @@ impl Deserializable for AccountStorageDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
+    use super::{
+        AccountStorageDelta, Deserializable, Serializable, StorageArrayDelta, StorageMapDelta,
+    };
     use crate::{ONE, ZERO};
 
     #[test]
@@ -172,6 +636,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_ok());
 
@@ -182,6 +648,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 255],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -192,6 +660,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 1],
             updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -202,6 +672,8 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (255, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -216,6 +688,8 @@ mod tests {
                 (5, [ONE, ONE, ONE, ZERO]),
                 (4, [ONE, ONE, ZERO, ZERO]),
             ],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -226,6 +700,130 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(2, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_maps: vec![],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn account_storage_delta_with_maps_validation() {
+        let map_delta = StorageMapDelta {
+            cleared_leaves: vec![[ONE, ZERO, ZERO, ZERO]],
+            updated_leaves: vec![([ONE, ONE, ZERO, ZERO], [ONE, ONE, ONE, ONE])],
+        };
+
+        let delta = AccountStorageDelta {
+            cleared_items: vec![1],
+            updated_items: vec![(2, [ONE, ONE, ONE, ONE])],
+            updated_maps: vec![(3, map_delta.clone())],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // map slot also present in updated items
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(3, [ONE, ONE, ONE, ONE])],
+            updated_maps: vec![(3, map_delta.clone())],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate in updated maps
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(3, map_delta.clone()), (3, map_delta)],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate across cleared and updated leaves
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(
+                3,
+                StorageMapDelta {
+                    cleared_leaves: vec![[ONE, ZERO, ZERO, ZERO]],
+                    updated_leaves: vec![([ONE, ZERO, ZERO, ZERO], [ONE, ONE, ONE, ONE])],
+                },
+            )],
+            updated_arrays: vec![],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn account_storage_delta_with_arrays_validation() {
+        let array_delta = StorageArrayDelta {
+            cleared_elements: vec![1],
+            updated_elements: vec![(2, [ONE, ONE, ONE, ONE])],
+        };
+
+        let delta = AccountStorageDelta {
+            cleared_items: vec![1],
+            updated_items: vec![],
+            updated_maps: vec![(2, StorageMapDelta::default())],
+            updated_arrays: vec![(3, array_delta.clone())],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // array slot also present in updated maps
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(3, StorageMapDelta::default())],
+            updated_arrays: vec![(3, array_delta.clone())],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate in updated arrays
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![(3, array_delta.clone()), (3, array_delta)],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // duplicate across cleared and updated elements
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![(
+                3,
+                StorageArrayDelta {
+                    cleared_elements: vec![1],
+                    updated_elements: vec![(1, [ONE, ONE, ONE, ONE])],
+                },
+            )],
         };
         assert!(delta.validate().is_err());
 
@@ -1,9 +1,13 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use super::{
     AccountDeltaError, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
     Serializable,
 };
+use crate::{
+    accounts::{AccountId, AccountType},
+    assets::{FungibleAsset, NonFungibleAsset},
+};
 
 // ACCOUNT VAULT DELTA
 // ================================================================================================
@@ -11,12 +15,17 @@ use super::{
 /// [AccountVaultDelta] stores the difference between the initial and final account vault states.
 ///
 /// The difference is represented as follows:
-/// - added_assets: a vector of assets that were added to the account vault.
-/// - removed_assets: a vector of assets that were removed from the account vault.
+/// - fungible_assets: a map from the ID of a fungible faucet to the net change in the amount of
+///   the asset issued by this faucet (positive value for added amounts, negative value for
+///   removed amounts).
+/// - added_non_fungible_assets: a set of non-fungible assets that were added to the account vault.
+/// - removed_non_fungible_assets: a set of non-fungible assets that were removed from the account
+///   vault.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountVaultDelta {
-    pub added_assets: Vec<Asset>,
-    pub removed_assets: Vec<Asset>,
+    pub fungible_assets: BTreeMap<AccountId, i64>,
+    pub added_non_fungible_assets: Vec<NonFungibleAsset>,
+    pub removed_non_fungible_assets: Vec<NonFungibleAsset>,
 }
 
 impl AccountVaultDelta {
@@ -24,38 +33,54 @@ impl AccountVaultDelta {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The number added assets is greater than [u16::MAX].
-    /// - The number of removed assets is greater than [u16::MAX].
-    /// - The same asset was added more than once, removed more than once, or both added and
-    ///   removed.
+    /// - The number of fungible assets, added non-fungible assets, or removed non-fungible assets
+    ///   is greater than [u16::MAX].
+    /// - Any of the fungible asset deltas is not for a fungible faucet, is zero, or its absolute
+    ///   value is greater than [FungibleAsset::MAX_AMOUNT].
+    /// - The same non-fungible asset was added more than once, removed more than once, or both
+    ///   added and removed.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
-        if self.added_assets.len() > u16::MAX as usize {
+        if self.fungible_assets.len() > u16::MAX as usize {
+            return Err(AccountDeltaError::TooManyFungibleAssets {
+                actual: self.fungible_assets.len(),
+                max: u16::MAX as usize,
+            });
+        } else if self.added_non_fungible_assets.len() > u16::MAX as usize {
             return Err(AccountDeltaError::TooManyAddedAsset {
-                actual: self.added_assets.len(),
+                actual: self.added_non_fungible_assets.len(),
                 max: u16::MAX as usize,
             });
-        } else if self.removed_assets.len() > u16::MAX as usize {
+        } else if self.removed_non_fungible_assets.len() > u16::MAX as usize {
             return Err(AccountDeltaError::TooManyRemovedAssets {
-                actual: self.removed_assets.len(),
+                actual: self.removed_non_fungible_assets.len(),
                 max: u16::MAX as usize,
             });
         }
 
-        // make sure all added assets are unique
-        for (pos, asset) in self.added_assets.iter().enumerate() {
-            if self.added_assets[..pos].iter().any(|a| a.is_same(asset)) {
-                return Err(AccountDeltaError::DuplicateVaultUpdate(*asset));
+        // make sure all fungible asset deltas are well formed
+        for (&faucet_id, &amount) in self.fungible_assets.iter() {
+            if faucet_id.account_type() != AccountType::FungibleFaucet {
+                return Err(AccountDeltaError::NotAFungibleFaucetId(faucet_id));
+            } else if amount == 0 {
+                return Err(AccountDeltaError::ZeroFungibleAssetDelta(faucet_id));
+            } else if amount.unsigned_abs() > FungibleAsset::MAX_AMOUNT {
+                return Err(AccountDeltaError::FungibleAssetDeltaTooBig(faucet_id));
             }
         }
 
-        // make sure all removed assets are the same
-        for (pos, asset) in self.removed_assets.iter().enumerate() {
-            if self.removed_assets[..pos].iter().any(|a| a.is_same(asset)) {
-                return Err(AccountDeltaError::DuplicateVaultUpdate(*asset));
+        // make sure all added non-fungible assets are unique
+        for (pos, asset) in self.added_non_fungible_assets.iter().enumerate() {
+            if self.added_non_fungible_assets[..pos].contains(asset) {
+                return Err(AccountDeltaError::DuplicateVaultUpdate((*asset).into()));
             }
+        }
 
-            if self.added_assets.iter().any(|a| a.is_same(asset)) {
-                return Err(AccountDeltaError::DuplicateVaultUpdate(*asset));
+        // make sure all removed non-fungible assets are unique and were not added
+        for (pos, asset) in self.removed_non_fungible_assets.iter().enumerate() {
+            if self.removed_non_fungible_assets[..pos].contains(asset)
+                || self.added_non_fungible_assets.contains(asset)
+            {
+                return Err(AccountDeltaError::DuplicateVaultUpdate((*asset).into()));
             }
         }
 
@@ -64,60 +89,259 @@ impl AccountVaultDelta {
 
     /// Returns true if this vault delta contains no updates.
     pub fn is_empty(&self) -> bool {
-        self.added_assets.is_empty() && self.removed_assets.is_empty()
+        self.fungible_assets.is_empty()
+            && self.added_non_fungible_assets.is_empty()
+            && self.removed_non_fungible_assets.is_empty()
+    }
+
+    /// Returns an iterator over the assets added to the account vault, i.e., the fungible assets
+    /// with a positive net amount and the added non-fungible assets.
+    ///
+    /// # Panics
+    /// Panics if this vault delta is not valid.
+    pub fn added_assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.fungible_assets
+            .iter()
+            .filter(|(_, &amount)| amount > 0)
+            .map(|(&faucet_id, &amount)| fungible_asset(faucet_id, amount))
+            .chain(self.added_non_fungible_assets.iter().map(|&asset| asset.into()))
+    }
+
+    /// Returns an iterator over the assets removed from the account vault, i.e., the fungible
+    /// assets with a negative net amount and the removed non-fungible assets.
+    ///
+    /// # Panics
+    /// Panics if this vault delta is not valid.
+    pub fn removed_assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.fungible_assets
+            .iter()
+            .filter(|(_, &amount)| amount < 0)
+            .map(|(&faucet_id, &amount)| fungible_asset(faucet_id, amount))
+            .chain(self.removed_non_fungible_assets.iter().map(|&asset| asset.into()))
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Tracks the addition of the provided asset to the account vault.
+    ///
+    /// Adding a fungible asset increases the net amount of the asset's faucet, and adding a
+    /// non-fungible asset which was previously removed cancels out the removal.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The net amount of the fungible asset would exceed [FungibleAsset::MAX_AMOUNT].
+    /// - The non-fungible asset has already been added.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), AccountDeltaError> {
+        match asset {
+            Asset::Fungible(asset) => {
+                self.update_fungible_amount(asset.faucet_id(), asset.amount() as i64)
+            },
+            Asset::NonFungible(asset) => update_non_fungible_assets(
+                asset,
+                &mut self.added_non_fungible_assets,
+                &mut self.removed_non_fungible_assets,
+            ),
+        }
+    }
+
+    /// Tracks the removal of the provided asset from the account vault.
+    ///
+    /// Removing a fungible asset decreases the net amount of the asset's faucet, and removing a
+    /// non-fungible asset which was previously added cancels out the addition.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The net amount of the fungible asset would be smaller than -[FungibleAsset::MAX_AMOUNT].
+    /// - The non-fungible asset has already been removed.
+    pub fn remove_asset(&mut self, asset: Asset) -> Result<(), AccountDeltaError> {
+        match asset {
+            Asset::Fungible(asset) => {
+                self.update_fungible_amount(asset.faucet_id(), -(asset.amount() as i64))
+            },
+            Asset::NonFungible(asset) => update_non_fungible_assets(
+                asset,
+                &mut self.removed_non_fungible_assets,
+                &mut self.added_non_fungible_assets,
+            ),
+        }
+    }
+
+    // DELTA OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a vault delta which combines the updates of this delta with the updates of the
+    /// `other` delta, assuming `other` is applied after this delta.
+    ///
+    /// Net amounts of fungible assets issued by the same faucet are summed up, and a non-fungible
+    /// asset added by one delta and removed by the other is omitted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset is added by both deltas or removed by both deltas.
+    /// - The net amount of a fungible asset exceeds [FungibleAsset::MAX_AMOUNT].
+    pub fn merge(&self, other: &Self) -> Result<Self, AccountDeltaError> {
+        let mut delta = self.clone();
+        for (&faucet_id, &amount) in other.fungible_assets.iter() {
+            delta.update_fungible_amount(faucet_id, amount)?;
+        }
+        for &asset in other.added_non_fungible_assets.iter() {
+            delta.add_asset(asset.into())?;
+        }
+        for &asset in other.removed_non_fungible_assets.iter() {
+            delta.remove_asset(asset.into())?;
+        }
+
+        delta.validate()?;
+
+        Ok(delta)
+    }
+
+    /// Returns a vault delta which reverts the updates of this delta, i.e., which removes all
+    /// assets added by this delta and adds back all assets removed by it.
+    pub fn invert(&self) -> Self {
+        Self {
+            fungible_assets: self
+                .fungible_assets
+                .iter()
+                .map(|(&faucet_id, &amount)| (faucet_id, -amount))
+                .collect(),
+            added_non_fungible_assets: self.removed_non_fungible_assets.clone(),
+            removed_non_fungible_assets: self.added_non_fungible_assets.clone(),
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided amount to the net amount of the specified faucet, removing the entry
+    /// for the faucet if the resulting net amount is zero.
+    fn update_fungible_amount(
+        &mut self,
+        faucet_id: AccountId,
+        amount: i64,
+    ) -> Result<(), AccountDeltaError> {
+        let current_amount = self.fungible_assets.get(&faucet_id).copied().unwrap_or_default();
+        let new_amount = current_amount
+            .checked_add(amount)
+            .filter(|amount| amount.unsigned_abs() <= FungibleAsset::MAX_AMOUNT)
+            .ok_or(AccountDeltaError::FungibleAssetDeltaTooBig(faucet_id))?;
+
+        if new_amount == 0 {
+            self.fungible_assets.remove(&faucet_id);
+        } else {
+            self.fungible_assets.insert(faucet_id, new_amount);
+        }
+
+        Ok(())
     }
 }
 
 impl Serializable for AccountVaultDelta {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        assert!(self.added_assets.len() <= u16::MAX as usize, "too many added assets");
-        target.write_u16(self.added_assets.len() as u16);
-        target.write_many(self.added_assets.iter());
+        assert!(self.fungible_assets.len() <= u16::MAX as usize, "too many fungible assets");
+        target.write_u16(self.fungible_assets.len() as u16);
+        for (faucet_id, &amount) in self.fungible_assets.iter() {
+            faucet_id.write_into(target);
+            target.write_u64(amount as u64);
+        }
+
+        assert!(
+            self.added_non_fungible_assets.len() <= u16::MAX as usize,
+            "too many added assets"
+        );
+        target.write_u16(self.added_non_fungible_assets.len() as u16);
+        for &asset in self.added_non_fungible_assets.iter() {
+            Asset::from(asset).write_into(target);
+        }
 
-        assert!(self.removed_assets.len() <= u16::MAX as usize, "too many removed assets");
-        target.write_u16(self.removed_assets.len() as u16);
-        target.write_many(self.removed_assets.iter());
+        assert!(
+            self.removed_non_fungible_assets.len() <= u16::MAX as usize,
+            "too many removed assets"
+        );
+        target.write_u16(self.removed_non_fungible_assets.len() as u16);
+        for &asset in self.removed_non_fungible_assets.iter() {
+            Asset::from(asset).write_into(target);
+        }
     }
 }
 
 impl Deserializable for AccountVaultDelta {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        // deserialize and validate added assets
-        let num_added_assets = source.read_u16()? as usize;
-        let mut added_assets: Vec<Asset> = Vec::with_capacity(num_added_assets);
-        for _ in 0..num_added_assets {
-            let asset = Asset::read_from(source)?;
-            if added_assets.iter().any(|a| a.is_same(&asset)) {
+        let num_fungible_assets = source.read_u16()? as usize;
+        let mut fungible_assets = BTreeMap::new();
+        for _ in 0..num_fungible_assets {
+            let faucet_id = AccountId::read_from(source)?;
+            let amount = source.read_u64()? as i64;
+            if fungible_assets.insert(faucet_id, amount).is_some() {
                 return Err(DeserializationError::InvalidValue(
-                    "asset added more than once".to_string(),
+                    "fungible asset updated more than once".to_string(),
                 ));
             }
-
-            added_assets.push(asset);
         }
 
-        // deserialize and validate removed assets
-        let num_removed_assets = source.read_u16()? as usize;
-        let mut removed_assets: Vec<Asset> = Vec::with_capacity(num_removed_assets);
-        for _ in 0..num_removed_assets {
-            let asset = Asset::read_from(source)?;
+        let added_non_fungible_assets = read_non_fungible_assets(source)?;
+        let removed_non_fungible_assets = read_non_fungible_assets(source)?;
 
-            if removed_assets.iter().any(|a| a.is_same(&asset)) {
-                return Err(DeserializationError::InvalidValue(
-                    "asset added more than once".to_string(),
-                ));
-            }
+        let delta = Self {
+            fungible_assets,
+            added_non_fungible_assets,
+            removed_non_fungible_assets,
+        };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
 
-            if added_assets.iter().any(|a| a.is_same(&asset)) {
+        Ok(delta)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a fungible asset with the absolute value of the provided net amount.
+fn fungible_asset(faucet_id: AccountId, amount: i64) -> Asset {
+    FungibleAsset::new(faucet_id, amount.unsigned_abs())
+        .expect("fungible asset delta must be valid")
+        .into()
+}
+
+/// Adds the provided non-fungible asset to `updated_assets`, unless the asset is contained in
+/// `reverted_assets`, in which case it is removed from there as the two updates cancel out.
+fn update_non_fungible_assets(
+    asset: NonFungibleAsset,
+    updated_assets: &mut Vec<NonFungibleAsset>,
+    reverted_assets: &mut Vec<NonFungibleAsset>,
+) -> Result<(), AccountDeltaError> {
+    if let Some(pos) = reverted_assets.iter().position(|a| a == &asset) {
+        reverted_assets.remove(pos);
+    } else if updated_assets.contains(&asset) {
+        return Err(AccountDeltaError::DuplicateVaultUpdate(asset.into()));
+    } else {
+        updated_assets.push(asset);
+    }
+
+    Ok(())
+}
+
+/// Reads a list of non-fungible assets prefixed by its length from the provided source.
+fn read_non_fungible_assets<R: ByteReader>(
+    source: &mut R,
+) -> Result<Vec<NonFungibleAsset>, DeserializationError> {
+    let num_assets = source.read_u16()? as usize;
+    let mut assets = Vec::with_capacity(num_assets);
+    for _ in 0..num_assets {
+        match Asset::read_from(source)? {
+            Asset::NonFungible(asset) => assets.push(asset),
+            Asset::Fungible(_) => {
                 return Err(DeserializationError::InvalidValue(
-                    "asset both added and removed".to_string(),
-                ));
-            }
-            removed_assets.push(asset);
+                    "expected a non-fungible asset".to_string(),
+                ))
+            },
         }
-
-        Ok(Self { added_assets, removed_assets })
     }
+
+    Ok(assets)
 }
 
 // TESTS
@@ -125,10 +349,13 @@ impl Deserializable for AccountVaultDelta {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
     use super::{AccountVaultDelta, Asset, Deserializable, Serializable};
     use crate::{
         accounts::{AccountId, AccountType},
         assets::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        AccountDeltaError,
     };
 
     #[test]
@@ -139,39 +366,64 @@ mod tests {
         let nffid1 = AccountId::new_dummy([0; 32], AccountType::NonFungibleFaucet);
         let nffid2 = AccountId::new_dummy([1; 32], AccountType::NonFungibleFaucet);
 
-        let asset1: Asset = FungibleAsset::new(ffid1, 10).unwrap().into();
-        let asset2: Asset = FungibleAsset::new(ffid1, 30).unwrap().into();
-        let asset3: Asset = FungibleAsset::new(ffid2, 20).unwrap().into();
-
-        let asset4: Asset =
+        let asset4 =
             NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid1, vec![1, 2, 3]).unwrap())
-                .unwrap()
-                .into();
-        let asset5: Asset =
+                .unwrap();
+        let asset5 =
             NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid1, vec![4, 5, 6]).unwrap())
-                .unwrap()
-                .into();
-        let asset6: Asset =
+                .unwrap();
+        let asset6 =
             NonFungibleAsset::new(&NonFungibleAssetDetails::new(nffid2, vec![7, 8, 9]).unwrap())
-                .unwrap()
-                .into();
+                .unwrap();
 
-        assert_eq!(asset5, Asset::read_from_bytes(&asset5.to_bytes()).unwrap());
+        let asset5_bytes = Asset::from(asset5).to_bytes();
+        assert_eq!(Asset::from(asset5), Asset::read_from_bytes(&asset5_bytes).unwrap());
 
         // control case
         let delta = AccountVaultDelta {
-            added_assets: vec![asset1, asset4, asset5],
-            removed_assets: vec![asset3, asset6],
+            fungible_assets: BTreeMap::from([(ffid1, 10), (ffid2, -20)]),
+            added_non_fungible_assets: vec![asset4, asset5],
+            removed_non_fungible_assets: vec![asset6],
         };
         assert!(delta.validate().is_ok());
 
         let bytes = delta.to_bytes();
         assert_eq!(AccountVaultDelta::read_from_bytes(&bytes), Ok(delta));
 
+        // zero fungible asset delta
+        let delta = AccountVaultDelta {
+            fungible_assets: BTreeMap::from([(ffid1, 10), (ffid2, 0)]),
+            ..Default::default()
+        };
+        assert_eq!(delta.validate(), Err(AccountDeltaError::ZeroFungibleAssetDelta(ffid2)));
+
+        let bytes = delta.to_bytes();
+        assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
+
+        // fungible asset delta for a non-fungible faucet
+        let delta = AccountVaultDelta {
+            fungible_assets: BTreeMap::from([(nffid1, 10)]),
+            ..Default::default()
+        };
+        assert_eq!(delta.validate(), Err(AccountDeltaError::NotAFungibleFaucetId(nffid1)));
+
+        let bytes = delta.to_bytes();
+        assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
+
+        // fungible asset delta which is too big
+        let delta = AccountVaultDelta {
+            fungible_assets: BTreeMap::from([(ffid1, i64::MIN)]),
+            ..Default::default()
+        };
+        assert_eq!(delta.validate(), Err(AccountDeltaError::FungibleAssetDeltaTooBig(ffid1)));
+
+        let bytes = delta.to_bytes();
+        assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
+
         // duplicate asset in added assets
         let delta = AccountVaultDelta {
-            added_assets: vec![asset1, asset4, asset5, asset2],
-            removed_assets: vec![],
+            added_non_fungible_assets: vec![asset4, asset5, asset4],
+            ..Default::default()
         };
         assert!(delta.validate().is_err());
 
@@ -180,8 +432,8 @@ mod tests {
 
         // duplicate asset in removed assets
         let delta = AccountVaultDelta {
-            added_assets: vec![],
-            removed_assets: vec![asset1, asset4, asset5, asset2],
+            removed_non_fungible_assets: vec![asset4, asset5, asset5],
+            ..Default::default()
         };
         assert!(delta.validate().is_err());
 
@@ -190,12 +442,42 @@ mod tests {
 
         // duplicate asset across added and removed assets
         let delta = AccountVaultDelta {
-            added_assets: vec![asset1, asset3],
-            removed_assets: vec![asset4, asset5, asset2],
+            added_non_fungible_assets: vec![asset4, asset6],
+            removed_non_fungible_assets: vec![asset5, asset6],
+            ..Default::default()
         };
         assert!(delta.validate().is_err());
 
         let bytes = delta.to_bytes();
         assert!(AccountVaultDelta::read_from_bytes(&bytes).is_err());
     }
+
+    #[test]
+    fn account_vault_delta_tracks_net_amounts() {
+        let faucet_id = AccountId::new_dummy([0; 32], AccountType::FungibleFaucet);
+        let fungible = |amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+        // receiving and sending the same fungible asset results in a single net amount
+        let mut delta = AccountVaultDelta::default();
+        delta.add_asset(fungible(100)).unwrap();
+        delta.remove_asset(fungible(30)).unwrap();
+        assert_eq!(delta.fungible_assets, BTreeMap::from([(faucet_id, 70)]));
+        assert_eq!(delta.added_assets().collect::<Vec<_>>(), vec![fungible(70)]);
+        assert_eq!(delta.removed_assets().count(), 0);
+
+        delta.remove_asset(fungible(100)).unwrap();
+        assert_eq!(delta.added_assets().count(), 0);
+        assert_eq!(delta.removed_assets().collect::<Vec<_>>(), vec![fungible(30)]);
+
+        // net amounts which cancel out are removed from the delta
+        delta.add_asset(fungible(30)).unwrap();
+        assert!(delta.is_empty());
+
+        // net amounts can not exceed the maximum amount of a fungible asset
+        delta.add_asset(fungible(FungibleAsset::MAX_AMOUNT)).unwrap();
+        assert_eq!(
+            delta.add_asset(fungible(1)),
+            Err(AccountDeltaError::FungibleAssetDeltaTooBig(faucet_id))
+        );
+    }
 }
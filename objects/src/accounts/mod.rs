@@ -15,13 +15,17 @@ mod code;
 pub use code::AccountCode;
 
 pub mod delta;
-pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+pub use delta::{
+    AccountDelta, AccountStorageDelta, AccountVaultDelta, StorageArrayDelta, StorageMapDelta,
+};
 
 mod seed;
 pub use seed::{get_account_seed, get_account_seed_single};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, SlotItem, StorageArray, StorageMap, StorageSlot, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -169,11 +173,11 @@ impl Account {
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
         // update vault; we don't check vault delta validity here because AccountDelta can contain
         // only valid vault deltas
-        for &asset in delta.vault().added_assets.iter() {
+        for asset in delta.vault().added_assets() {
             self.vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
         }
 
-        for &asset in delta.vault().removed_assets.iter() {
+        for asset in delta.vault().removed_assets() {
             self.vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
         }
 
@@ -446,9 +450,17 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![0],
             updated_items: vec![(1, word)],
+            updated_maps: vec![],
+            updated_arrays: vec![],
         };
 
-        let vault_delta = AccountVaultDelta { added_assets, removed_assets };
+        let mut vault_delta = AccountVaultDelta::default();
+        for asset in added_assets {
+            vault_delta.add_asset(asset).unwrap();
+        }
+        for asset in removed_assets {
+            vault_delta.remove_asset(asset).unwrap();
+        }
 
         AccountDelta::new(storage_delta, vault_delta, Some(nonce)).unwrap()
     }
@@ -517,7 +529,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn empty_account_delta_with_incremented_nonce() {
         // build account
         let init_nonce = Felt::new(1);
@@ -533,7 +544,10 @@ mod tests {
         )
         .unwrap();
 
-        // apply delta
-        account.apply_delta(&account_delta).unwrap()
+        // apply delta; only the nonce of the account is updated
+        let storage = account.storage().clone();
+        account.apply_delta(&account_delta).unwrap();
+        assert_eq!(account.nonce(), final_nonce);
+        assert_eq!(account.storage(), &storage);
     }
 }
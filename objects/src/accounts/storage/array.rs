@@ -0,0 +1,323 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+
+use super::{
+    AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable, Word,
+};
+use crate::{
+    accounts::delta::StorageArrayDelta,
+    crypto::merkle::{EmptySubtreeRoots, InnerNodeInfo, MerklePath, NodeIndex},
+    Hasher, EMPTY_WORD,
+};
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// A fixed-size array of words stored in a single account storage slot.
+///
+/// The array is backed by a sparse Merkle tree of the specified depth, and the root of this tree is
+/// the value stored in the account storage slot the array occupies. An array of depth `d` contains
+/// `2^d` elements, and an element which has never been set (or which has been set to
+/// [EMPTY_WORD]) is equal to [EMPTY_WORD].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageArray {
+    depth: u8,
+    root: Digest,
+    elements: BTreeMap<u64, Word>,
+    nodes: BTreeMap<NodeIndex, Digest>,
+}
+
+impl StorageArray {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Minimum depth of a storage array.
+    pub const MIN_DEPTH: u8 = 2;
+
+    /// Maximum depth of a storage array.
+    pub const MAX_DEPTH: u8 = 64;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageArray] of the specified depth with all elements set to [EMPTY_WORD].
+    ///
+    /// # Errors
+    /// Returns an error if the depth is smaller than 2 or greater than 64.
+    pub fn new(depth: u8) -> Result<Self, AccountError> {
+        if !(Self::MIN_DEPTH..=Self::MAX_DEPTH).contains(&depth) {
+            return Err(AccountError::StorageArrayInvalidDepth(depth));
+        }
+
+        Ok(Self {
+            depth,
+            root: *EmptySubtreeRoots::entry(depth, 0),
+            elements: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Returns a new [StorageArray] of the specified depth instantiated with the provided
+    /// elements.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is smaller than 2 or greater than 64.
+    /// - Any of the element indexes is out of bounds for an array of the specified depth.
+    /// - The provided elements contain multiple values for the same index.
+    pub fn with_elements(
+        depth: u8,
+        elements: impl IntoIterator<Item = (u64, Word)>,
+    ) -> Result<Self, AccountError> {
+        let mut array = Self::new(depth)?;
+        let mut inserted = BTreeSet::new();
+        for (index, value) in elements {
+            if !inserted.insert(index) {
+                return Err(AccountError::DuplicateStorageArrayElements(index));
+            }
+            array.insert(index, value)?;
+        }
+
+        Ok(array)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of this array.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the root of the Merkle tree backing this array.
+    pub fn root(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns the element located at the specified index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds for this array.
+    pub fn get(&self, index: u64) -> Result<Word, AccountError> {
+        self.node_index(index)?;
+        Ok(self.elements.get(&index).copied().unwrap_or(EMPTY_WORD))
+    }
+
+    /// Returns the Merkle path from the element located at the specified index to the root of
+    /// this array.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds for this array.
+    pub fn open(&self, index: u64) -> Result<MerklePath, AccountError> {
+        let mut node_index = self.node_index(index)?;
+
+        let mut path = Vec::with_capacity(self.depth as usize);
+        while !node_index.is_root() {
+            path.push(self.get_node(node_index.sibling()));
+            node_index.move_up();
+        }
+
+        Ok(MerklePath::new(path))
+    }
+
+    /// Returns an iterator over the non-empty elements of this array and their indexes.
+    pub fn elements(&self) -> impl Iterator<Item = (&u64, &Word)> {
+        self.elements.iter()
+    }
+
+    /// Returns an iterator over the non-empty inner nodes of the Merkle tree backing this array.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.nodes
+            .iter()
+            .filter(|(index, _)| index.depth() < self.depth)
+            .map(|(index, value)| InnerNodeInfo {
+                value: *value,
+                left: self.get_node(index.left_child()),
+                right: self.get_node(index.right_child()),
+            })
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the element located at the specified index to the provided value and returns the
+    /// value previously located at this index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is out of bounds for this array.
+    pub fn insert(&mut self, index: u64, value: Word) -> Result<Word, AccountError> {
+        let mut node_index = self.node_index(index)?;
+
+        let old_value = if value == EMPTY_WORD {
+            self.elements.remove(&index)
+        } else {
+            self.elements.insert(index, value)
+        };
+
+        // update the nodes on the path from the element to the root
+        let mut node = Digest::from(value);
+        self.set_node(node_index, node);
+        while !node_index.is_root() {
+            let sibling = self.get_node(node_index.sibling());
+            let [left, right] = node_index.build_node(node, sibling);
+            node = Hasher::merge(&[left, right]);
+            node_index.move_up();
+            self.set_node(node_index, node);
+        }
+        self.root = node;
+
+        Ok(old_value.unwrap_or(EMPTY_WORD))
+    }
+
+    /// Applies the provided delta to this array and returns the new root of the array.
+    ///
+    /// # Errors
+    /// Returns an error if any of the indexes in the delta is out of bounds for this array.
+    pub(super) fn apply_delta(
+        &mut self,
+        delta: &StorageArrayDelta,
+    ) -> Result<Digest, AccountError> {
+        for &index in delta.cleared_elements.iter() {
+            self.insert(index, EMPTY_WORD)?;
+        }
+
+        for &(index, value) in delta.updated_elements.iter() {
+            self.insert(index, value)?;
+        }
+
+        Ok(self.root)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the leaf node which holds the element at the specified index.
+    fn node_index(&self, index: u64) -> Result<NodeIndex, AccountError> {
+        NodeIndex::new(self.depth, index)
+            .map_err(|_| AccountError::StorageArrayIndexOutOfBounds(self.depth, index))
+    }
+
+    /// Returns the node at the specified index, or the root of an empty subtree if the node is
+    /// not set.
+    fn get_node(&self, index: NodeIndex) -> Digest {
+        match self.nodes.get(&index) {
+            Some(node) => *node,
+            None => *EmptySubtreeRoots::entry(self.depth, index.depth()),
+        }
+    }
+
+    /// Sets the node at the specified index, removing it if it is the root of an empty subtree.
+    fn set_node(&mut self, index: NodeIndex, node: Digest) {
+        if node == *EmptySubtreeRoots::entry(self.depth, index.depth()) {
+            self.nodes.remove(&index);
+        } else {
+            self.nodes.insert(index, node);
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageArray {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth);
+
+        assert!(
+            self.elements.len() <= u32::MAX as usize,
+            "too many elements in the storage array"
+        );
+        target.write_u32(self.elements.len() as u32);
+        for (index, value) in self.elements.iter() {
+            target.write_u64(*index);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageArray {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+
+        let num_elements = source.read_u32()? as usize;
+        let mut elements = Vec::with_capacity(num_elements);
+        for _ in 0..num_elements {
+            let index = source.read_u64()?;
+            let value = Word::read_from(source)?;
+            elements.push((index, value));
+        }
+
+        Self::with_elements(depth, elements)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Serializable, StorageArray};
+    use crate::{
+        crypto::merkle::{MerkleTree, NodeIndex},
+        Word, ONE, ZERO,
+    };
+
+    #[test]
+    fn storage_array_matches_merkle_tree() {
+        let elements: [(u64, Word); 3] = [
+            (0, [ONE, ZERO, ZERO, ZERO]),
+            (5, [ONE, ONE, ZERO, ZERO]),
+            (7, [ONE, ONE, ONE, ONE]),
+        ];
+        let array = StorageArray::with_elements(3, elements).unwrap();
+
+        let mut leaves = [[ZERO; 4]; 8];
+        for (index, value) in elements {
+            leaves[index as usize] = value;
+        }
+        let tree = MerkleTree::new(leaves).unwrap();
+        assert_eq!(array.root(), tree.root());
+
+        for index in 0..8 {
+            assert_eq!(array.get(index).unwrap(), leaves[index as usize]);
+            assert_eq!(
+                array.open(index).unwrap(),
+                tree.get_path(NodeIndex::new(3, index).unwrap()).unwrap()
+            );
+        }
+
+        // clearing an element restores the root of the tree without this element
+        let mut array = array;
+        array.insert(5, [ZERO; 4]).unwrap();
+        leaves[5] = [ZERO; 4];
+        let tree = MerkleTree::new(leaves).unwrap();
+        assert_eq!(array.root(), tree.root());
+    }
+
+    #[test]
+    fn storage_array_validation() {
+        assert!(StorageArray::new(1).is_err());
+        assert!(StorageArray::new(65).is_err());
+
+        let mut array = StorageArray::new(2).unwrap();
+        assert!(array.get(4).is_err());
+        assert!(array.insert(4, [ONE; 4]).is_err());
+
+        assert!(StorageArray::with_elements(2, [(1, [ONE; 4]), (1, [ZERO; 4])]).is_err());
+    }
+
+    #[test]
+    fn storage_array_serialization() {
+        let array =
+            StorageArray::with_elements(64, [(0, [ONE; 4]), (u64::MAX, [ONE, ZERO, ONE, ZERO])])
+                .unwrap();
+        let bytes = array.to_bytes();
+        assert_eq!(array, StorageArray::read_from_bytes(&bytes).unwrap());
+    }
+}
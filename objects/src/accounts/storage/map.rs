@@ -0,0 +1,150 @@
+use alloc::{string::ToString, vec::Vec};
+
+use super::{
+    AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Serializable, Word,
+};
+use crate::{
+    accounts::delta::StorageMapDelta,
+    crypto::merkle::{InnerNodeInfo, LeafIndex, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
+    EMPTY_WORD,
+};
+
+// STORAGE MAP
+// ================================================================================================
+
+/// A key-value map stored in a single account storage slot.
+///
+/// The map is backed by a Sparse Merkle Tree, and the root of this tree is the value stored in the
+/// account storage slot the map occupies. Both keys and values of the map are words, and a key
+/// which has never been set (or which has been set to [EMPTY_WORD]) maps to [EMPTY_WORD].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageMap {
+    map: Smt,
+}
+
+impl StorageMap {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Depth of the storage map tree.
+    pub const STORAGE_MAP_TREE_DEPTH: u8 = SMT_DEPTH;
+
+    /// The default value of empty leaves.
+    pub const EMPTY_VALUE: Word = Smt::EMPTY_VALUE;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new, empty [StorageMap].
+    pub fn new() -> Self {
+        Self { map: Smt::new() }
+    }
+
+    /// Returns a new [StorageMap] instantiated with the provided entries.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain multiple values for the same key.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Digest, Word)>,
+    ) -> Result<Self, AccountError> {
+        let map = Smt::with_entries(entries).map_err(AccountError::DuplicateStorageMapKeys)?;
+        Ok(Self { map })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the Sparse Merkle Tree backing this map.
+    pub fn root(&self) -> Digest {
+        self.map.root()
+    }
+
+    /// Returns the value located under the specified key.
+    ///
+    /// If the key has not been set, [EMPTY_WORD] is returned.
+    pub fn get_value(&self, key: &Digest) -> Word {
+        self.map.get_value(key)
+    }
+
+    /// Returns the leaf located under the specified key.
+    pub fn get_leaf(&self, key: &Digest) -> SmtLeaf {
+        self.map.get_leaf(key)
+    }
+
+    /// Returns an opening of the leaf associated with the specified key.
+    pub fn open(&self, key: &Digest) -> SmtProof {
+        self.map.open(key)
+    }
+
+    /// Returns an iterator over the non-empty leaves of this map.
+    pub fn leaves(&self) -> impl Iterator<Item = (LeafIndex<SMT_DEPTH>, &SmtLeaf)> {
+        self.map.leaves()
+    }
+
+    /// Returns an iterator over the key-value pairs of this map.
+    pub fn entries(&self) -> impl Iterator<Item = &(Digest, Word)> {
+        self.map.entries()
+    }
+
+    /// Returns an iterator over the inner nodes of the Sparse Merkle Tree backing this map.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.map.inner_nodes()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided value under the specified key and returns the value previously
+    /// located under this key.
+    ///
+    /// Inserting [EMPTY_WORD] removes the key from the map.
+    pub fn insert(&mut self, key: Digest, value: Word) -> Word {
+        self.map.insert(key, value)
+    }
+
+    /// Applies the provided delta to this map and returns the new root of the map.
+    pub(super) fn apply_delta(&mut self, delta: &StorageMapDelta) -> Digest {
+        for &key in delta.cleared_leaves.iter() {
+            self.map.insert(key.into(), EMPTY_WORD);
+        }
+
+        for &(key, value) in delta.updated_leaves.iter() {
+            self.map.insert(key.into(), value);
+        }
+
+        self.map.root()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let entries = self.map.entries().collect::<Vec<_>>();
+
+        assert!(entries.len() <= u32::MAX as usize, "too many entries in the storage map");
+        target.write_u32(entries.len() as u32);
+        for (key, value) in entries {
+            key.write_into(target);
+            value.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for StorageMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_u32()? as usize;
+        let mut entries = Vec::with_capacity(num_entries);
+        for _ in 0..num_entries {
+            let key = Digest::read_from(source)?;
+            let value = Word::read_from(source)?;
+            entries.push((key, value));
+        }
+
+        Self::with_entries(entries)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
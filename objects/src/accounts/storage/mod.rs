@@ -4,11 +4,17 @@ use super::{
     AccountError, AccountStorageDelta, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, Word,
 };
-use crate::crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt};
+use crate::crypto::merkle::{LeafIndex, MerklePath, NodeIndex, SimpleSmt};
 
 mod slot;
 pub use slot::StorageSlotType;
 
+mod map;
+pub use map::StorageMap;
+
+mod array;
+pub use array::StorageArray;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -89,10 +95,16 @@ impl StorageSlot {
 ///
 /// Storage slots are stored in a simple Sparse Merkle Tree of depth 8. Slot 255 is always reserved
 /// and contains information about slot types of all other slots.
+///
+/// For map slots, the slot value is the root of the map, and the contents of the map are kept in
+/// a [StorageMap] associated with the slot index. Similarly, for array slots the slot value is the
+/// root of the array, and the contents of the array are kept in a [StorageArray].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorage {
     slots: SimpleSmt<STORAGE_TREE_DEPTH>,
     layout: Vec<StorageSlotType>,
+    maps: BTreeMap<u8, StorageMap>,
+    arrays: BTreeMap<u8, StorageArray>,
 }
 
 impl AccountStorage {
@@ -111,6 +123,14 @@ impl AccountStorage {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new instance of account storage initialized with the provided items.
+    ///
+    /// The contents of map and array slots are set via [AccountStorage::with_maps()] and
+    /// [AccountStorage::with_arrays()] respectively.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - One of the items is placed in the reserved layout commitment slot.
+    /// - The provided items contain more than one item for the same slot.
     pub fn new(items: Vec<SlotItem>) -> Result<AccountStorage, AccountError> {
         // initialize storage layout
         let mut layout = vec![StorageSlotType::default(); Self::NUM_STORAGE_SLOTS];
@@ -142,7 +162,63 @@ impl AccountStorage {
         let slots = SimpleSmt::<STORAGE_TREE_DEPTH>::with_leaves(entries)
             .map_err(AccountError::DuplicateStorageItems)?;
 
-        Ok(Self { slots, layout })
+        Ok(Self {
+            slots,
+            layout,
+            maps: BTreeMap::new(),
+            arrays: BTreeMap::new(),
+        })
+    }
+
+    /// Returns this account storage with the contents of its map slots set to the provided maps.
+    ///
+    /// # Errors
+    /// Returns an error if a map is provided for a slot which is not a map slot, or if the root
+    /// of the map does not match the value of the slot.
+    pub fn with_maps(mut self, maps: BTreeMap<u8, StorageMap>) -> Result<Self, AccountError> {
+        for (&index, map) in maps.iter() {
+            let slot_type = self.layout[index as usize];
+            if !matches!(slot_type, StorageSlotType::Map { .. }) {
+                return Err(AccountError::StorageSlotNotMapSlot(index, slot_type));
+            }
+
+            if self.get_item(index) != map.root() {
+                return Err(AccountError::StorageMapRootMismatch(index));
+            }
+        }
+
+        self.maps = maps;
+        Ok(self)
+    }
+
+    /// Returns this account storage with the contents of its array slots set to the provided
+    /// arrays.
+    ///
+    /// # Errors
+    /// Returns an error if an array is provided for a slot which is not an array slot, or if the
+    /// depth or the root of the array does not match the type or the value of the slot.
+    pub fn with_arrays(mut self, arrays: BTreeMap<u8, StorageArray>) -> Result<Self, AccountError> {
+        for (&index, array) in arrays.iter() {
+            match self.layout[index as usize] {
+                StorageSlotType::Array { depth, .. } => {
+                    if depth != array.depth() {
+                        return Err(AccountError::StorageArrayDepthMismatch {
+                            slot: index,
+                            expected: depth,
+                            actual: array.depth(),
+                        });
+                    }
+                },
+                slot_type => return Err(AccountError::StorageSlotNotArraySlot(index, slot_type)),
+            }
+
+            if self.get_item(index) != array.root() {
+                return Err(AccountError::StorageArrayRootMismatch(index));
+            }
+        }
+
+        self.arrays = arrays;
+        Ok(self)
     }
 
     // PUBLIC ACCESSORS
@@ -162,6 +238,37 @@ impl AccountStorage {
         self.slots.get_node(item_index).expect("index is u8 - index within range")
     }
 
+    /// Returns a value located under the specified key in the map stored at the specified index.
+    ///
+    /// If the key is not present in the map, [ZERO; 4] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not a map slot, or if the contents
+    /// of the map are not available in this storage.
+    pub fn get_map_item(&self, index: u8, key: Word) -> Result<Word, AccountError> {
+        self.get_map(index).map(|map| map.get_value(&key.into()))
+    }
+
+    /// Returns the element located at the specified position in the array stored at the
+    /// specified index.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not an array slot, if the contents
+    /// of the array are not available in this storage, or if the position is out of bounds.
+    pub fn get_array_item(&self, index: u8, position: u64) -> Result<Word, AccountError> {
+        self.get_array(index)?.get(position)
+    }
+
+    /// Returns the Merkle path from the element located at the specified position in the array
+    /// stored at the specified index to the root of this array.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not an array slot, if the contents
+    /// of the array are not available in this storage, or if the position is out of bounds.
+    pub fn open_array_item(&self, index: u8, position: u64) -> Result<MerklePath, AccountError> {
+        self.get_array(index)?.open(position)
+    }
+
     /// Returns a reference to the Sparse Merkle Tree that backs the storage slots.
     pub fn slots(&self) -> &SimpleSmt<STORAGE_TREE_DEPTH> {
         &self.slots
@@ -177,6 +284,16 @@ impl AccountStorage {
         Hasher::hash_elements(&self.layout.iter().map(Felt::from).collect::<Vec<_>>())
     }
 
+    /// Returns the storage maps of this storage keyed by the index of the slot they occupy.
+    pub fn maps(&self) -> &BTreeMap<u8, StorageMap> {
+        &self.maps
+    }
+
+    /// Returns the storage arrays of this storage keyed by the index of the slot they occupy.
+    pub fn arrays(&self) -> &BTreeMap<u8, StorageArray> {
+        &self.arrays
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -190,6 +307,22 @@ impl AccountStorage {
     /// - The delta implies an update to a reserved account slot.
     /// - The updates violate storage layout constraints.
     pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+        for (slot_idx, map_delta) in delta.updated_maps.iter() {
+            let map = self.get_map_mut(*slot_idx)?;
+            let new_root = map.apply_delta(map_delta);
+
+            let index = LeafIndex::new(*slot_idx as u64).expect("index is u8 - index within range");
+            self.slots.insert(index, new_root.into());
+        }
+
+        for (slot_idx, array_delta) in delta.updated_arrays.iter() {
+            let array = self.get_array_mut(*slot_idx)?;
+            let new_root = array.apply_delta(array_delta)?;
+
+            let index = LeafIndex::new(*slot_idx as u64).expect("index is u8 - index within range");
+            self.slots.insert(index, new_root.into());
+        }
+
         for &slot_idx in delta.cleared_items.iter() {
             self.set_item(slot_idx, Word::default())?;
         }
@@ -232,6 +365,95 @@ impl AccountStorage {
         let slot_value = self.slots.insert(index, value);
         Ok(slot_value)
     }
+
+    /// Sets a value under the specified key in the map stored at the specified index, and returns
+    /// the value previously located under this key.
+    ///
+    /// The slot at the specified index is updated with the new root of the map.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not a map slot, or if the contents
+    /// of the map are not available in this storage.
+    pub fn set_map_item(
+        &mut self,
+        index: u8,
+        key: Word,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let map = self.get_map_mut(index)?;
+        let old_value = map.insert(key.into(), value);
+        let new_root = map.root();
+
+        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        self.slots.insert(index, new_root.into());
+        Ok(old_value)
+    }
+
+    /// Sets the element located at the specified position in the array stored at the specified
+    /// index, and returns the value previously located at this position.
+    ///
+    /// The slot at the specified index is updated with the new root of the array.
+    ///
+    /// # Errors
+    /// Returns an error if the slot at the specified index is not an array slot, if the contents
+    /// of the array are not available in this storage, or if the position is out of bounds.
+    pub fn set_array_item(
+        &mut self,
+        index: u8,
+        position: u64,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let array = self.get_array_mut(index)?;
+        let old_value = array.insert(position, value)?;
+        let new_root = array.root();
+
+        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+        self.slots.insert(index, new_root.into());
+        Ok(old_value)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the map stored at the specified index.
+    fn get_map(&self, index: u8) -> Result<&StorageMap, AccountError> {
+        match self.layout[index as usize] {
+            StorageSlotType::Map { .. } => {
+                self.maps.get(&index).ok_or(AccountError::StorageMapNotFound(index))
+            },
+            slot_type => Err(AccountError::StorageSlotNotMapSlot(index, slot_type)),
+        }
+    }
+
+    /// Returns a mutable reference to the map stored at the specified index.
+    fn get_map_mut(&mut self, index: u8) -> Result<&mut StorageMap, AccountError> {
+        match self.layout[index as usize] {
+            StorageSlotType::Map { .. } => {
+                self.maps.get_mut(&index).ok_or(AccountError::StorageMapNotFound(index))
+            },
+            slot_type => Err(AccountError::StorageSlotNotMapSlot(index, slot_type)),
+        }
+    }
+
+    /// Returns a reference to the array stored at the specified index.
+    fn get_array(&self, index: u8) -> Result<&StorageArray, AccountError> {
+        match self.layout[index as usize] {
+            StorageSlotType::Array { .. } => {
+                self.arrays.get(&index).ok_or(AccountError::StorageArrayNotFound(index))
+            },
+            slot_type => Err(AccountError::StorageSlotNotArraySlot(index, slot_type)),
+        }
+    }
+
+    /// Returns a mutable reference to the array stored at the specified index.
+    fn get_array_mut(&mut self, index: u8) -> Result<&mut StorageArray, AccountError> {
+        match self.layout[index as usize] {
+            StorageSlotType::Array { .. } => {
+                self.arrays.get_mut(&index).ok_or(AccountError::StorageArrayNotFound(index))
+            },
+            slot_type => Err(AccountError::StorageSlotNotArraySlot(index, slot_type)),
+        }
+    }
 }
 
 // SERIALIZATION
@@ -271,6 +493,20 @@ impl Serializable for AccountStorage {
             target.write_u8(idx as u8);
             target.write(value);
         }
+
+        // serialize the contents of storage maps
+        target.write_u8(self.maps.len() as u8);
+        for (&idx, map) in self.maps.iter() {
+            target.write_u8(idx);
+            map.write_into(target);
+        }
+
+        // serialize the contents of storage arrays
+        target.write_u8(self.arrays.len() as u8);
+        for (&idx, array) in self.arrays.iter() {
+            target.write_u8(idx);
+            array.write_into(target);
+        }
     }
 }
 
@@ -299,7 +535,28 @@ impl Deserializable for AccountStorage {
             });
         }
 
-        Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        // read the contents of storage maps
+        let mut maps = BTreeMap::new();
+        let num_maps = source.read_u8()?;
+        for _ in 0..num_maps {
+            let index = source.read_u8()?;
+            let map = StorageMap::read_from(source)?;
+            maps.insert(index, map);
+        }
+
+        // read the contents of storage arrays
+        let mut arrays = BTreeMap::new();
+        let num_arrays = source.read_u8()?;
+        for _ in 0..num_arrays {
+            let index = source.read_u8()?;
+            let array = StorageArray::read_from(source)?;
+            arrays.insert(index, array);
+        }
+
+        Self::new(items)
+            .and_then(|storage| storage.with_maps(maps))
+            .and_then(|storage| storage.with_arrays(arrays))
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
 
@@ -308,12 +565,24 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use super::{
-        AccountStorage, Deserializable, Serializable, SlotItem, StorageSlot, StorageSlotType,
+        AccountStorage, Deserializable, Serializable, SlotItem, StorageArray, StorageMap,
+        StorageSlot, StorageSlotType,
+    };
+    use crate::{
+        accounts::{AccountStorageDelta, StorageArrayDelta, StorageMapDelta},
+        Digest, ONE, ZERO,
     };
-    use crate::{ONE, ZERO};
+
+    fn storage_with_map(map: StorageMap) -> Result<AccountStorage, crate::AccountError> {
+        AccountStorage::new(vec![SlotItem {
+            index: 1,
+            slot: StorageSlot::new_map(*map.root()),
+        }])
+        .and_then(|storage| storage.with_maps(BTreeMap::from([(1, map)])))
+    }
 
     #[test]
     fn account_storage_serialization() {
@@ -375,4 +644,118 @@ mod tests {
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
+
+    #[test]
+    fn account_storage_with_maps() {
+        let map = StorageMap::with_entries([
+            (Digest::new([ONE, ZERO, ZERO, ZERO]), [ONE, ONE, ONE, ONE]),
+            (Digest::new([ONE, ONE, ZERO, ZERO]), [ONE, ONE, ZERO, ZERO]),
+        ])
+        .unwrap();
+
+        // storage with a map
+        let storage = storage_with_map(map).unwrap();
+        assert_eq!(storage.get_map_item(1, [ONE, ZERO, ZERO, ZERO]).unwrap(), [ONE, ONE, ONE, ONE]);
+        assert_eq!(storage.get_map_item(1, [ZERO, ZERO, ZERO, ONE]).unwrap(), [ZERO; 4]);
+        assert!(storage.get_map_item(0, [ONE, ZERO, ZERO, ZERO]).is_err());
+
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // map which does not match the root stored in the slot
+        assert!(matches!(
+            storage.with_maps(BTreeMap::from([(1, StorageMap::new())])),
+            Err(crate::AccountError::StorageMapRootMismatch(1))
+        ));
+    }
+
+    #[test]
+    fn account_storage_apply_map_delta() {
+        let map = StorageMap::with_entries([
+            (Digest::new([ONE, ZERO, ZERO, ZERO]), [ONE, ONE, ONE, ONE]),
+            (Digest::new([ONE, ONE, ZERO, ZERO]), [ONE, ONE, ZERO, ZERO]),
+        ])
+        .unwrap();
+        let mut storage = storage_with_map(map.clone()).unwrap();
+
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![(
+                1,
+                StorageMapDelta {
+                    cleared_leaves: vec![[ONE, ZERO, ZERO, ZERO]],
+                    updated_leaves: vec![([ZERO, ZERO, ZERO, ONE], [ONE, ZERO, ONE, ZERO])],
+                },
+            )],
+            updated_arrays: vec![],
+        };
+        storage.apply_delta(&delta).unwrap();
+
+        let mut expected_map = map;
+        expected_map.insert(Digest::new([ONE, ZERO, ZERO, ZERO]), [ZERO; 4]);
+        expected_map.insert(Digest::new([ZERO, ZERO, ZERO, ONE]), [ONE, ZERO, ONE, ZERO]);
+
+        assert_eq!(storage.get_item(1), expected_map.root());
+        assert_eq!(storage.maps().get(&1), Some(&expected_map));
+        assert_eq!(storage.get_map_item(1, [ONE, ZERO, ZERO, ZERO]).unwrap(), [ZERO; 4]);
+    }
+
+    #[test]
+    fn account_storage_with_arrays() {
+        let array = StorageArray::with_elements(3, [(1, [ONE, ONE, ONE, ONE])]).unwrap();
+        let storage = AccountStorage::new(vec![
+            SlotItem {
+                index: 0,
+                slot: StorageSlot::new_value([ONE, ONE, ONE, ONE]),
+            },
+            SlotItem {
+                index: 1,
+                slot: StorageSlot::new_array(*array.root(), 3),
+            },
+        ])
+        .unwrap();
+
+        // arrays must match the type and the value of the slot
+        assert!(storage.clone().with_arrays(BTreeMap::from([(0, array.clone())])).is_err());
+        assert!(storage
+            .clone()
+            .with_arrays(BTreeMap::from([(1, StorageArray::new(3).unwrap())]))
+            .is_err());
+        assert!(matches!(
+            storage
+                .clone()
+                .with_arrays(BTreeMap::from([(1, StorageArray::new(4).unwrap())])),
+            Err(crate::AccountError::StorageArrayDepthMismatch { slot: 1, expected: 3, actual: 4 })
+        ));
+
+        let mut storage = storage.with_arrays(BTreeMap::from([(1, array.clone())])).unwrap();
+        assert_eq!(storage.get_array_item(1, 1).unwrap(), [ONE, ONE, ONE, ONE]);
+        assert_eq!(storage.get_array_item(1, 2).unwrap(), [ZERO; 4]);
+        assert_eq!(storage.open_array_item(1, 2).unwrap(), array.open(2).unwrap());
+        assert!(storage.get_array_item(1, 8).is_err());
+        assert!(storage.get_array_item(0, 1).is_err());
+
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // apply a delta to the array
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_maps: vec![],
+            updated_arrays: vec![(
+                1,
+                StorageArrayDelta {
+                    cleared_elements: vec![1],
+                    updated_elements: vec![(7, [ONE, ZERO, ONE, ZERO])],
+                },
+            )],
+        };
+        storage.apply_delta(&delta).unwrap();
+
+        let expected_array = StorageArray::with_elements(3, [(7, [ONE, ZERO, ONE, ZERO])]).unwrap();
+        assert_eq!(storage.get_item(1), expected_array.root());
+        assert_eq!(storage.arrays().get(&1), Some(&expected_array));
+    }
 }
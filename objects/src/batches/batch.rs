@@ -0,0 +1,662 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+
+use super::BatchNoteTree;
+use crate::{
+    accounts::AccountId,
+    notes::{NoteId, Nullifier},
+    transaction::{AccountDetails, OutputNote, ProvenTransaction, TransactionId},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountError, Digest, Felt, Hasher, TransactionBatchError, MAX_NOTES_PER_BATCH,
+    MAX_TRANSACTIONS_PER_BATCH,
+};
+
+// TRANSACTION BATCH
+// ================================================================================================
+
+/// A set of proven transactions which are aggregated together and committed to the chain as a
+/// single unit.
+///
+/// A batch contains:
+/// - Consolidated updates of all accounts modified by the transactions in the batch.
+/// - Nullifiers of all notes consumed by the transactions in the batch.
+/// - All notes created by the transactions in the batch, together with the [BatchNoteTree]
+///   committing to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionBatch {
+    /// IDs of the transactions in this batch, in the order in which they were added.
+    transactions: Vec<TransactionId>,
+
+    /// Consolidated account updates mapped by the IDs of the updated accounts.
+    updated_accounts: BTreeMap<AccountId, BatchAccountUpdate>,
+
+    /// Nullifiers of all notes consumed by the transactions in this batch.
+    produced_nullifiers: Vec<Nullifier>,
+
+    /// All notes created by the transactions in this batch.
+    output_notes: Vec<OutputNote>,
+
+    /// The tree committing to the notes created in this batch.
+    output_notes_tree: BatchNoteTree,
+
+    /// The number of the last block in which this batch can be included, i.e., the smallest
+    /// expiration block number of the transactions in this batch.
+    expiration_block_num: u32,
+}
+
+impl TransactionBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionBatch] built from the provided proven transactions.
+    ///
+    /// `block_ref` is the number of the block the batch is built against, i.e., the earliest block
+    /// in which the batch can be included.
+    ///
+    /// If the same account is updated by several transactions, the transactions must be provided
+    /// in the order in which they were executed, and the updates are consolidated into a single
+    /// [BatchAccountUpdate].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of transactions is greater than [MAX_TRANSACTIONS_PER_BATCH].
+    /// - A transaction expires before the reference block, i.e., `block_ref` is greater than the
+    ///   expiration block number of the transaction.
+    /// - The same note is consumed by more than one transaction in the batch.
+    /// - The same note is created by more than one transaction in the batch.
+    /// - The number of notes created in the batch is greater than [MAX_NOTES_PER_BATCH].
+    /// - A transaction updating an account does not start from the state in which the previous
+    ///   transaction against the same account left it.
+    pub fn new(txs: &[ProvenTransaction], block_ref: u32) -> Result<Self, TransactionBatchError> {
+        if txs.len() > MAX_TRANSACTIONS_PER_BATCH {
+            return Err(TransactionBatchError::TooManyTransactions {
+                max: MAX_TRANSACTIONS_PER_BATCH,
+                actual: txs.len(),
+            });
+        }
+
+        if let Some(tx) = txs.iter().find(|tx| block_ref > tx.expiration_block_num()) {
+            return Err(TransactionBatchError::ExpiredTransaction {
+                tx_id: tx.id(),
+                expiration_block_num: tx.expiration_block_num(),
+                block_num: block_ref,
+            });
+        }
+
+        let mut updated_accounts = BTreeMap::<AccountId, BatchAccountUpdate>::new();
+        for tx in txs {
+            match updated_accounts.get_mut(&tx.account_id()) {
+                Some(update) => update.merge_tx(tx)?,
+                None => {
+                    updated_accounts.insert(tx.account_id(), BatchAccountUpdate::from_tx(tx));
+                },
+            }
+        }
+
+        let mut produced_nullifiers = Vec::new();
+        let mut output_notes = Vec::new();
+        for tx in txs {
+            produced_nullifiers.extend(tx.input_notes().iter().copied());
+            output_notes.extend(tx.output_notes().iter().cloned());
+        }
+
+        Self::from_parts(
+            txs.iter().map(ProvenTransaction::id).collect(),
+            updated_accounts,
+            produced_nullifiers,
+            output_notes,
+            txs.iter()
+                .map(ProvenTransaction::expiration_block_num)
+                .min()
+                .unwrap_or(u32::MAX),
+        )
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch.
+    ///
+    /// The ID is computed as a sequential hash of the IDs of the transactions in the batch.
+    pub fn id(&self) -> Digest {
+        let elements: Vec<Felt> = self
+            .transactions
+            .iter()
+            .flat_map(|tx_id| tx_id.as_elements())
+            .copied()
+            .collect();
+        Hasher::hash_elements(&elements)
+    }
+
+    /// Returns the IDs of the transactions in this batch.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns an iterator over the IDs of the accounts updated in this batch and the
+    /// consolidated updates of these accounts.
+    pub fn updated_accounts(&self) -> impl Iterator<Item = (&AccountId, &BatchAccountUpdate)> {
+        self.updated_accounts.iter()
+    }
+
+    /// Returns the nullifiers of all notes consumed by the transactions in this batch.
+    pub fn produced_nullifiers(&self) -> &[Nullifier] {
+        &self.produced_nullifiers
+    }
+
+    /// Returns all notes created by the transactions in this batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the tree committing to the notes created in this batch.
+    pub fn output_notes_tree(&self) -> &BatchNoteTree {
+        &self.output_notes_tree
+    }
+
+    /// Returns the root of the tree committing to the notes created in this batch.
+    pub fn output_notes_root(&self) -> Digest {
+        self.output_notes_tree.root()
+    }
+
+    /// Returns the number of the last block in which this batch can be included.
+    ///
+    /// This is the smallest expiration block number of the transactions in this batch, or
+    /// `u32::MAX` if the batch is empty.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks nullifiers and output notes for duplicates, builds the note tree, and returns the
+    /// resulting [TransactionBatch].
+    fn from_parts(
+        transactions: Vec<TransactionId>,
+        updated_accounts: BTreeMap<AccountId, BatchAccountUpdate>,
+        produced_nullifiers: Vec<Nullifier>,
+        output_notes: Vec<OutputNote>,
+        expiration_block_num: u32,
+    ) -> Result<Self, TransactionBatchError> {
+        let mut nullifier_set = BTreeSet::new();
+        for nullifier in produced_nullifiers.iter() {
+            if !nullifier_set.insert(nullifier) {
+                return Err(TransactionBatchError::DuplicateNullifier(*nullifier));
+            }
+        }
+
+        if output_notes.len() > MAX_NOTES_PER_BATCH {
+            return Err(TransactionBatchError::TooManyNotes {
+                max: MAX_NOTES_PER_BATCH,
+                actual: output_notes.len(),
+            });
+        }
+
+        let mut note_set = BTreeSet::<NoteId>::new();
+        for note in output_notes.iter() {
+            if !note_set.insert(note.id()) {
+                return Err(TransactionBatchError::DuplicateOutputNote(note.id()));
+            }
+        }
+
+        let output_notes_tree = BatchNoteTree::with_contiguous_leaves(
+            output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .map_err(TransactionBatchError::NotesTreeError)?;
+
+        Ok(Self {
+            transactions,
+            updated_accounts,
+            produced_nullifiers,
+            output_notes,
+            output_notes_tree,
+            expiration_block_num,
+        })
+    }
+}
+
+// BATCH ACCOUNT UPDATE
+// ================================================================================================
+
+/// Describes the consolidated update of a single account made by the transactions in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAccountUpdate {
+    /// The hash of the account before the first transaction in the batch was executed.
+    initial_state_hash: Digest,
+
+    /// The hash of the account after the last transaction in the batch was executed.
+    final_state_hash: Digest,
+
+    /// IDs of the transactions which updated the account, in the order of execution.
+    transactions: Vec<TransactionId>,
+
+    /// Consolidated details of the changes made to the account by all of the transactions. This
+    /// is `None` for off-chain accounts.
+    details: Option<AccountDetails>,
+}
+
+impl BatchAccountUpdate {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the account before the first transaction in the batch was executed.
+    pub fn initial_state_hash(&self) -> Digest {
+        self.initial_state_hash
+    }
+
+    /// Returns the hash of the account after the last transaction in the batch was executed.
+    pub fn final_state_hash(&self) -> Digest {
+        self.final_state_hash
+    }
+
+    /// Returns the IDs of the transactions which updated the account.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the consolidated details of the changes made to the account by all of the
+    /// transactions, or `None` for off-chain accounts.
+    ///
+    /// For an account created in the batch these are the full details of the account after the
+    /// last transaction; otherwise, these are the deltas of the transactions merged together.
+    pub fn details(&self) -> Option<&AccountDetails> {
+        self.details.as_ref()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BatchAccountUpdate] describing the account update made by the provided
+    /// transaction.
+    fn from_tx(tx: &ProvenTransaction) -> Self {
+        Self {
+            initial_state_hash: tx.initial_account_hash(),
+            final_state_hash: tx.final_account_hash(),
+            transactions: vec![tx.id()],
+            details: tx.account_details().cloned(),
+        }
+    }
+
+    /// Merges the account update made by the provided transaction into this update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction was not executed against the final state of this update.
+    /// - The account details of the transaction cannot be merged into the details of this update.
+    fn merge_tx(&mut self, tx: &ProvenTransaction) -> Result<(), TransactionBatchError> {
+        if self.final_state_hash != tx.initial_account_hash() {
+            return Err(TransactionBatchError::AccountUpdateConflict {
+                account_id: tx.account_id(),
+                expected: self.final_state_hash,
+                actual: tx.initial_account_hash(),
+            });
+        }
+
+        self.details = merge_details(tx.account_id(), self.details.take(), tx.account_details())?;
+        self.final_state_hash = tx.final_account_hash();
+        self.transactions.push(tx.id());
+
+        Ok(())
+    }
+}
+
+/// Merges the account details of a transaction into the consolidated details of the previous
+/// transactions against the same account.
+///
+/// Deltas are folded via [AccountDelta::merge()](crate::accounts::AccountDelta::merge), and a
+/// delta following the full details of a newly created account is applied to that account.
+///
+/// # Errors
+/// Returns an error if:
+/// - The details are present for only one of the two, or the transaction contains the full
+///   details of an account which was already updated in the batch.
+/// - The deltas cannot be merged, or the delta cannot be applied to the new account.
+fn merge_details(
+    account_id: AccountId,
+    details: Option<AccountDetails>,
+    tx_details: Option<&AccountDetails>,
+) -> Result<Option<AccountDetails>, TransactionBatchError> {
+    let merge_error =
+        |error| TransactionBatchError::AccountDetailsMergeFailed { account_id, error };
+
+    match (details, tx_details) {
+        (None, None) => Ok(None),
+        (Some(AccountDetails::Full(mut account)), Some(AccountDetails::Delta(delta))) => {
+            account.apply_delta(delta).map_err(merge_error)?;
+            Ok(Some(AccountDetails::Full(account)))
+        },
+        (Some(AccountDetails::Delta(delta)), Some(AccountDetails::Delta(tx_delta))) => {
+            let delta = delta
+                .merge(tx_delta)
+                .map_err(|err| merge_error(AccountError::InvalidAccountDelta(err)))?;
+            Ok(Some(AccountDetails::Delta(delta)))
+        },
+        _ => Err(TransactionBatchError::InconsistentAccountDetails(account_id)),
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BatchAccountUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.initial_state_hash.write_into(target);
+        self.final_state_hash.write_into(target);
+        self.transactions.write_into(target);
+        self.details.write_into(target);
+    }
+}
+
+impl Deserializable for BatchAccountUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let initial_state_hash = Digest::read_from(source)?;
+        let final_state_hash = Digest::read_from(source)?;
+        let transactions = <Vec<TransactionId>>::read_from(source)?;
+        let details = <Option<AccountDetails>>::read_from(source)?;
+
+        Ok(Self {
+            initial_state_hash,
+            final_state_hash,
+            transactions,
+            details,
+        })
+    }
+}
+
+impl Serializable for TransactionBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.transactions.write_into(target);
+        self.updated_accounts.write_into(target);
+        self.produced_nullifiers.write_into(target);
+        self.output_notes.write_into(target);
+        self.expiration_block_num.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let transactions = <Vec<TransactionId>>::read_from(source)?;
+        let updated_accounts = <BTreeMap<AccountId, BatchAccountUpdate>>::read_from(source)?;
+        let produced_nullifiers = <Vec<Nullifier>>::read_from(source)?;
+        let output_notes = <Vec<OutputNote>>::read_from(source)?;
+        let expiration_block_num = u32::read_from(source)?;
+
+        Self::from_parts(
+            transactions,
+            updated_accounts,
+            produced_nullifiers,
+            output_notes,
+            expiration_block_num,
+        )
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_air::HashFunction;
+    use miden_verifier::ExecutionProof;
+    use winter_air::proof::StarkProof;
+
+    use super::{TransactionBatch, MAX_TRANSACTIONS_PER_BATCH};
+    use crate::{
+        accounts::{
+            AccountDelta, AccountId, AccountStorageDelta, AccountVaultDelta,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        assets::{Asset, FungibleAsset},
+        notes::{NoteEnvelope, NoteId, NoteMetadata, NoteType, Nullifier},
+        transaction::{AccountDetails, OutputNote, ProvenTransaction, ProvenTransactionBuilder},
+        utils::serde::{Deserializable, Serializable},
+        Digest, Felt, TransactionBatchError, ONE, ZERO,
+    };
+
+    #[test]
+    fn batch_consolidates_account_updates() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx1 = build_tx(account_id, [0, 1], &[1], &[1]);
+        let tx2 = build_tx(account_id, [1, 2], &[2], &[2]);
+
+        let other_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let tx3 = build_tx(other_account_id, [5, 6], &[3], &[]);
+
+        let batch = TransactionBatch::new(&[tx1.clone(), tx2.clone(), tx3.clone()], 0).unwrap();
+
+        assert_eq!(batch.transactions(), &[tx1.id(), tx2.id(), tx3.id()]);
+        assert_eq!(batch.produced_nullifiers().len(), 3);
+        assert_eq!(batch.output_notes().len(), 2);
+
+        let updates = batch.updated_accounts().collect::<Vec<_>>();
+        assert_eq!(updates.len(), 2);
+
+        let (_, update) = updates.iter().find(|(id, _)| **id == account_id).unwrap();
+        assert_eq!(update.initial_state_hash(), tx1.initial_account_hash());
+        assert_eq!(update.final_state_hash(), tx2.final_account_hash());
+        assert_eq!(update.transactions(), &[tx1.id(), tx2.id()]);
+
+        let (_, update) = updates.iter().find(|(id, _)| **id == other_account_id).unwrap();
+        assert_eq!(update.initial_state_hash(), tx3.initial_account_hash());
+        assert_eq!(update.final_state_hash(), tx3.final_account_hash());
+    }
+
+    #[test]
+    fn batch_merges_account_deltas() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = |amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+        let mut vault_delta = AccountVaultDelta::default();
+        vault_delta.add_asset(asset(100)).unwrap();
+        let delta_1 =
+            AccountDelta::new(AccountStorageDelta::default(), vault_delta, Some(Felt::new(2)))
+                .unwrap();
+
+        let mut vault_delta = AccountVaultDelta::default();
+        vault_delta.remove_asset(asset(30)).unwrap();
+        let delta_2 =
+            AccountDelta::new(AccountStorageDelta::default(), vault_delta, Some(Felt::new(3)))
+                .unwrap();
+
+        let tx1 = build_tx_with_delta(account_id, [0, 1], delta_1.clone());
+        let tx2 = build_tx_with_delta(account_id, [1, 2], delta_2.clone());
+        let batch = TransactionBatch::new(&[tx1, tx2], 0).unwrap();
+
+        // the deltas of both transactions are consolidated into a single delta
+        let (_, update) = batch.updated_accounts().next().unwrap();
+        assert_eq!(
+            update.details(),
+            Some(&AccountDetails::Delta(delta_1.merge(&delta_2).unwrap()))
+        );
+
+        let deserialized = TransactionBatch::read_from_bytes(&batch.to_bytes()).unwrap();
+        assert_eq!(deserialized, batch);
+    }
+
+    #[test]
+    fn batch_rejects_conflicting_account_updates() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx1 = build_tx(account_id, [0, 1], &[1], &[]);
+        let tx2 = build_tx(account_id, [0, 2], &[2], &[]);
+
+        assert_eq!(
+            TransactionBatch::new(&[tx1.clone(), tx2], 0),
+            Err(TransactionBatchError::AccountUpdateConflict {
+                account_id,
+                expected: tx1.final_account_hash(),
+                actual: tx1.initial_account_hash(),
+            })
+        );
+    }
+
+    #[test]
+    fn batch_rejects_expired_transactions() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let other_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let tx1 = build_expiring_tx(account_id, [0, 1], 5);
+        let tx2 = build_expiring_tx(other_account_id, [2, 3], 7);
+
+        // the batch expires together with the transaction which expires first
+        let batch = TransactionBatch::new(&[tx1.clone(), tx2.clone()], 5).unwrap();
+        assert_eq!(batch.expiration_block_num(), 5);
+
+        assert_eq!(
+            TransactionBatch::new(&[tx1.clone(), tx2], 6),
+            Err(TransactionBatchError::ExpiredTransaction {
+                tx_id: tx1.id(),
+                expiration_block_num: 5,
+                block_num: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn batch_rejects_duplicates() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let other_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+        // the same note consumed twice
+        let tx1 = build_tx(account_id, [0, 1], &[1], &[]);
+        let tx2 = build_tx(other_account_id, [0, 1], &[1], &[]);
+        assert_eq!(
+            TransactionBatch::new(&[tx1, tx2], 0),
+            Err(TransactionBatchError::DuplicateNullifier(nullifier(1)))
+        );
+
+        // the same note created twice
+        let tx1 = build_tx(account_id, [0, 1], &[1], &[1]);
+        let tx2 = build_tx(other_account_id, [0, 1], &[2], &[1]);
+        assert!(matches!(
+            TransactionBatch::new(&[tx1, tx2], 0),
+            Err(TransactionBatchError::DuplicateOutputNote(_))
+        ));
+    }
+
+    #[test]
+    fn batch_rejects_too_many_transactions() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let txs = (0..=MAX_TRANSACTIONS_PER_BATCH as u64)
+            .map(|i| build_tx(account_id, [i, i + 1], &[i], &[]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            TransactionBatch::new(&txs, 0),
+            Err(TransactionBatchError::TooManyTransactions {
+                max: MAX_TRANSACTIONS_PER_BATCH,
+                actual: MAX_TRANSACTIONS_PER_BATCH + 1
+            })
+        );
+    }
+
+    #[test]
+    fn batch_serialization() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx1 = build_tx(account_id, [0, 1], &[1], &[1, 2]);
+        let tx2 = build_tx(account_id, [1, 2], &[2], &[3]);
+        let batch = TransactionBatch::new(&[tx1, tx2], 0).unwrap();
+
+        let deserialized = TransactionBatch::read_from_bytes(&batch.to_bytes()).unwrap();
+        assert_eq!(deserialized, batch);
+        assert_eq!(deserialized.id(), batch.id());
+        assert_eq!(deserialized.output_notes_root(), batch.output_notes_root());
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a proven transaction against the specified account which moves it between the
+    /// provided states, consumes the notes with the provided nullifier seeds and creates the notes
+    /// with the provided ID seeds.
+    fn build_tx(
+        account_id: AccountId,
+        states: [u64; 2],
+        nullifiers: &[u64],
+        notes: &[u64],
+    ) -> ProvenTransaction {
+        let metadata = NoteMetadata::new(account_id, NoteType::OffChain, 0.into(), ZERO).unwrap();
+        let output_notes = notes.iter().map(|seed| {
+            let note_id = NoteId::from(digest(*seed));
+            OutputNote::Private(NoteEnvelope::new(note_id, metadata).unwrap())
+        });
+
+        ProvenTransactionBuilder::new(
+            account_id,
+            digest(states[0]),
+            digest(states[1]),
+            Digest::default(),
+            u32::MAX,
+            dummy_proof(),
+        )
+        .add_input_notes(nullifiers.iter().map(|seed| nullifier(*seed)))
+        .add_output_notes(output_notes)
+        .build()
+        .unwrap()
+    }
+
+    /// Builds a proven transaction against the specified on-chain account which moves it between
+    /// the provided states by applying the provided delta.
+    fn build_tx_with_delta(
+        account_id: AccountId,
+        states: [u64; 2],
+        delta: AccountDelta,
+    ) -> ProvenTransaction {
+        ProvenTransactionBuilder::new(
+            account_id,
+            digest(states[0]),
+            digest(states[1]),
+            Digest::default(),
+            u32::MAX,
+            dummy_proof(),
+        )
+        .account_details(AccountDetails::Delta(delta))
+        .add_input_notes([nullifier(states[1])])
+        .build()
+        .unwrap()
+    }
+
+    /// Builds a proven transaction against the specified off-chain account which moves it between
+    /// the provided states and expires at the specified block.
+    fn build_expiring_tx(
+        account_id: AccountId,
+        states: [u64; 2],
+        expiration_block_num: u32,
+    ) -> ProvenTransaction {
+        ProvenTransactionBuilder::new(
+            account_id,
+            digest(states[0]),
+            digest(states[1]),
+            Digest::default(),
+            expiration_block_num,
+            dummy_proof(),
+        )
+        .add_input_notes([nullifier(states[1])])
+        .build()
+        .unwrap()
+    }
+
+    fn digest(seed: u64) -> Digest {
+        Digest::new([Felt::new(seed), ONE, ZERO, ONE])
+    }
+
+    fn nullifier(seed: u64) -> Nullifier {
+        Nullifier::from(digest(seed))
+    }
+
+    fn dummy_proof() -> ExecutionProof {
+        ExecutionProof::new(StarkProof::new_dummy(), HashFunction::Blake3_192)
+    }
+}
@@ -0,0 +1,403 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::{BlockHeader, BlockNoteTree, Digest, Felt, Hasher, ZERO};
+use crate::{
+    accounts::AccountId,
+    batches::TransactionBatch,
+    crypto::merkle::{LeafIndex, Mmr, SimpleSmt, Smt},
+    notes::Nullifier,
+    BlockError, ACCOUNT_TREE_DEPTH, EMPTY_WORD, GENESIS_BLOCK, MAX_BATCHES_PER_BLOCK,
+};
+
+// BLOCK BUILDER
+// ================================================================================================
+
+/// A reference implementation of block production.
+///
+/// The builder maintains the state of the chain required to produce blocks, namely:
+/// - The account tree, which maps account IDs to the hashes of the current account states.
+/// - The nullifier tree, which maps nullifiers of consumed notes to the numbers of the blocks in
+///   which the notes were consumed.
+/// - The chain MMR, which contains hashes of all blocks produced so far.
+///
+/// Each call to [BlockBuilder::build_block()] applies the provided transaction batches to this
+/// state and produces a [BlockHeader] committing to the resulting state.
+///
+/// Block proofs are not yet supported, and thus the proof hash of the produced blocks is always
+/// set to `Digest::default()`.
+#[derive(Debug, Clone)]
+pub struct BlockBuilder {
+    account_tree: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    nullifier_tree: Smt,
+    chain_mmr: Mmr,
+    latest_header: Option<BlockHeader>,
+}
+
+impl BlockBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockBuilder] with empty chain state.
+    ///
+    /// The first block produced by the returned builder is the genesis block.
+    pub fn new() -> Self {
+        Self {
+            account_tree: SimpleSmt::new().expect("valid tree depth"),
+            nullifier_tree: Smt::new(),
+            chain_mmr: Mmr::new(),
+            latest_header: None,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the tree of account state hashes.
+    pub fn account_tree(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
+        &self.account_tree
+    }
+
+    /// Returns the tree of nullifiers of consumed notes.
+    pub fn nullifier_tree(&self) -> &Smt {
+        &self.nullifier_tree
+    }
+
+    /// Returns the MMR of hashes of all blocks produced so far.
+    pub fn chain_mmr(&self) -> &Mmr {
+        &self.chain_mmr
+    }
+
+    /// Returns the header of the latest produced block, or None if no blocks were produced yet.
+    pub fn latest_header(&self) -> Option<&BlockHeader> {
+        self.latest_header.as_ref()
+    }
+
+    /// Returns the number of the next block to be produced.
+    pub fn next_block_num(&self) -> u32 {
+        self.latest_header.map_or(GENESIS_BLOCK, |header| header.block_num() + 1)
+    }
+
+    // BLOCK PRODUCTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the provided transaction batches to the chain state and returns the header of the
+    /// resulting block together with the tree of notes created in the block.
+    ///
+    /// The roots in the returned header are computed as follows:
+    /// - `chain_root` commits to all blocks produced before this one.
+    /// - `account_root` and `nullifier_root` commit to the respective trees after the batches were
+    ///   applied.
+    /// - `note_root` is the root of the [BlockNoteTree] built from the notes of all batches.
+    /// - `batch_root` is a sequential hash of the IDs of all batches in the block.
+    ///
+    /// The chain state is modified only if the block was produced successfully.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block is the genesis block and the batches are not empty.
+    /// - The number of batches is greater than [MAX_BATCHES_PER_BLOCK].
+    /// - A batch contains a transaction which expired before this block, i.e., the number of this
+    ///   block is greater than the expiration block number of the batch.
+    /// - An account update does not start from the current state of the account.
+    /// - A note consumed in the block was already consumed, or is consumed more than once.
+    /// - The note tree of the block could not be built.
+    pub fn build_block(
+        &mut self,
+        batches: &[TransactionBatch],
+        timestamp: Felt,
+    ) -> Result<(BlockHeader, BlockNoteTree), BlockError> {
+        if batches.len() > MAX_BATCHES_PER_BLOCK {
+            return Err(BlockError::TooManyBatches {
+                max: MAX_BATCHES_PER_BLOCK,
+                actual: batches.len(),
+            });
+        }
+
+        let block_num = self.next_block_num();
+
+        // nullifiers are mapped to the number of the block in which they were produced, and a
+        // nullifier mapped to the genesis block would be indistinguishable from an empty leaf
+        if block_num == GENESIS_BLOCK && !batches.is_empty() {
+            return Err(BlockError::TransactionsInGenesisBlock);
+        }
+        if let Some(batch) = batches.iter().find(|batch| block_num > batch.expiration_block_num()) {
+            return Err(BlockError::ExpiredBatch {
+                batch_id: batch.id(),
+                expiration_block_num: batch.expiration_block_num(),
+                block_num,
+            });
+        }
+
+        let updated_accounts = self.get_updated_accounts(batches)?;
+        let produced_nullifiers = self.get_produced_nullifiers(batches)?;
+
+        let note_tree = BlockNoteTree::with_entries(batches.iter().enumerate().flat_map(
+            |(batch_idx, batch)| {
+                batch.output_notes().iter().enumerate().map(move |(note_idx, note)| {
+                    (batch_idx, note_idx, (note.id().into(), *note.metadata()))
+                })
+            },
+        ))
+        .map_err(BlockError::NotesTreeError)?;
+
+        // all checks passed; apply the block to the chain state
+        for (account_id, account_hash) in updated_accounts {
+            self.account_tree.insert(LeafIndex::from(account_id), account_hash.into());
+        }
+        for nullifier in produced_nullifiers {
+            self.nullifier_tree
+                .insert(nullifier.inner(), [block_num.into(), ZERO, ZERO, ZERO]);
+        }
+
+        let batch_ids: Vec<Felt> =
+            batches.iter().flat_map(|batch| batch.id().as_elements().to_vec()).collect();
+
+        let header = BlockHeader::new(
+            self.latest_header.map_or(Digest::default(), |header| header.hash()),
+            block_num,
+            self.chain_mmr
+                .peaks(self.chain_mmr.forest())
+                .expect("valid forest")
+                .hash_peaks(),
+            self.account_tree.root(),
+            self.nullifier_tree.root(),
+            note_tree.root(),
+            Hasher::hash_elements(&batch_ids),
+            Digest::default(),
+            ZERO,
+            timestamp,
+        );
+
+        self.chain_mmr.add(header.hash());
+        self.latest_header = Some(header);
+
+        Ok((header, note_tree))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the final states of the accounts updated by the provided batches.
+    ///
+    /// # Errors
+    /// Returns an error if an account update does not start from the current state of the account.
+    fn get_updated_accounts(
+        &self,
+        batches: &[TransactionBatch],
+    ) -> Result<BTreeMap<AccountId, Digest>, BlockError> {
+        let mut updated_accounts = BTreeMap::<AccountId, Digest>::new();
+        for (account_id, update) in batches.iter().flat_map(TransactionBatch::updated_accounts) {
+            let current_hash = match updated_accounts.get(account_id) {
+                Some(hash) => *hash,
+                None => self.account_tree.get_leaf(&LeafIndex::from(*account_id)).into(),
+            };
+
+            if current_hash != update.initial_state_hash() {
+                return Err(BlockError::AccountUpdateConflict {
+                    account_id: *account_id,
+                    expected: current_hash,
+                    actual: update.initial_state_hash(),
+                });
+            }
+
+            updated_accounts.insert(*account_id, update.final_state_hash());
+        }
+
+        Ok(updated_accounts)
+    }
+
+    /// Returns the nullifiers produced by the provided batches.
+    ///
+    /// # Errors
+    /// Returns an error if a nullifier is already present in the nullifier tree, or is produced
+    /// more than once.
+    fn get_produced_nullifiers(
+        &self,
+        batches: &[TransactionBatch],
+    ) -> Result<Vec<Nullifier>, BlockError> {
+        let mut produced_nullifiers = BTreeSet::new();
+        for nullifier in batches.iter().flat_map(|batch| batch.produced_nullifiers()) {
+            if self.nullifier_tree.get_value(&nullifier.inner()) != EMPTY_WORD
+                || !produced_nullifiers.insert(*nullifier)
+            {
+                return Err(BlockError::DuplicateNullifier(*nullifier));
+            }
+        }
+
+        Ok(produced_nullifiers.into_iter().collect())
+    }
+}
+
+impl Default for BlockBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_air::HashFunction;
+    use miden_verifier::ExecutionProof;
+    use winter_air::proof::StarkProof;
+
+    use super::BlockBuilder;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_OFF_CHAIN_SENDER},
+        batches::TransactionBatch,
+        block::BlockNoteTree,
+        crypto::merkle::{LeafIndex, Mmr, SimpleSmt, Smt},
+        notes::{NoteEnvelope, NoteId, NoteMetadata, NoteType, Nullifier},
+        transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder},
+        BlockError, Digest, Felt, Word, ACCOUNT_TREE_DEPTH, ONE, ZERO,
+    };
+
+    #[test]
+    fn build_blocks() {
+        let mut builder = BlockBuilder::new();
+
+        // genesis block commits to empty chain state
+        let (genesis, _) = builder.build_block(&[], ZERO).unwrap();
+        assert_eq!(genesis.block_num(), 0);
+        assert_eq!(genesis.prev_hash(), Digest::default());
+        assert_eq!(genesis.chain_root(), Mmr::new().peaks(0).unwrap().hash_peaks());
+        assert_eq!(genesis.account_root(), SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().unwrap().root());
+        assert_eq!(genesis.nullifier_root(), Smt::new().root());
+
+        // the next block creates a new account, consumes a note and creates a note
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let metadata = NoteMetadata::new(account_id, NoteType::OffChain, 0.into(), ZERO).unwrap();
+        let note =
+            OutputNote::Private(NoteEnvelope::new(NoteId::from(digest(3)), metadata).unwrap());
+        let tx = build_tx(
+            account_id,
+            [Digest::default(), digest(1)],
+            &[nullifier(2)],
+            &[note.clone()],
+            u32::MAX,
+        );
+        let batch = TransactionBatch::new(&[tx], 1).unwrap();
+
+        let (header, note_tree) = builder.build_block(&[batch.clone()], ONE).unwrap();
+        assert_eq!(header.block_num(), 1);
+        assert_eq!(header.prev_hash(), genesis.hash());
+        assert_eq!(header.timestamp(), ONE);
+
+        let mut chain_mmr = Mmr::new();
+        chain_mmr.add(genesis.hash());
+        assert_eq!(header.chain_root(), chain_mmr.peaks(1).unwrap().hash_peaks());
+
+        let account_tree = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves([(
+            LeafIndex::<ACCOUNT_TREE_DEPTH>::from(account_id).value(),
+            digest(1).into(),
+        )])
+        .unwrap();
+        assert_eq!(header.account_root(), account_tree.root());
+
+        let mut nullifier_tree = Smt::new();
+        nullifier_tree.insert(nullifier(2).inner(), [ONE, ZERO, ZERO, ZERO]);
+        assert_eq!(header.nullifier_root(), nullifier_tree.root());
+
+        let expected_note_tree =
+            BlockNoteTree::with_entries([(0, 0, (note.id().into(), *note.metadata()))]).unwrap();
+        assert_eq!(note_tree, expected_note_tree);
+        assert_eq!(header.note_root(), expected_note_tree.root());
+
+        assert_eq!(builder.latest_header(), Some(&header));
+        assert_eq!(builder.chain_mmr().forest(), 2);
+    }
+
+    #[test]
+    fn build_block_rejects_invalid_batches() {
+        let mut builder = BlockBuilder::new();
+        let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx =
+            build_tx(account_id, [Digest::default(), digest(1)], &[nullifier(2)], &[], u32::MAX);
+
+        // the genesis block can not contain transactions
+        assert_eq!(
+            builder.build_block(&[TransactionBatch::new(&[tx.clone()], 0).unwrap()], ZERO),
+            Err(BlockError::TransactionsInGenesisBlock)
+        );
+        builder.build_block(&[], ZERO).unwrap();
+
+        builder.build_block(&[TransactionBatch::new(&[tx], 1).unwrap()], ONE).unwrap();
+
+        // the account is not in the state the transaction was executed against
+        let tx = build_tx(account_id, [digest(5), digest(6)], &[], &[], u32::MAX);
+        assert_eq!(
+            builder.build_block(&[TransactionBatch::new(&[tx], 2).unwrap()], ONE),
+            Err(BlockError::AccountUpdateConflict {
+                account_id,
+                expected: digest(1),
+                actual: digest(5),
+            })
+        );
+
+        // the note was already consumed in the previous block
+        let tx = build_tx(account_id, [digest(1), digest(2)], &[nullifier(2)], &[], u32::MAX);
+        assert_eq!(
+            builder.build_block(&[TransactionBatch::new(&[tx], 2).unwrap()], ONE),
+            Err(BlockError::DuplicateNullifier(nullifier(2)))
+        );
+
+        // the transaction expired in the previous block
+        let tx = build_tx(account_id, [digest(1), digest(2)], &[], &[], 1);
+        let batch = TransactionBatch::new(&[tx], 1).unwrap();
+        assert_eq!(
+            builder.build_block(&[batch.clone()], ONE),
+            Err(BlockError::ExpiredBatch {
+                batch_id: batch.id(),
+                expiration_block_num: 1,
+                block_num: 2,
+            })
+        );
+
+        // failed attempts do not modify the chain state
+        assert_eq!(builder.next_block_num(), 2);
+        assert_eq!(
+            builder.account_tree().get_leaf(&LeafIndex::from(account_id)),
+            Word::from(digest(1))
+        );
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a proven transaction against the specified off-chain account which moves it between
+    /// the provided states, consumes the notes with the provided nullifiers, creates the provided
+    /// notes, and expires at the specified block.
+    fn build_tx(
+        account_id: AccountId,
+        states: [Digest; 2],
+        nullifiers: &[Nullifier],
+        notes: &[OutputNote],
+        expiration_block_num: u32,
+    ) -> ProvenTransaction {
+        ProvenTransactionBuilder::new(
+            account_id,
+            states[0],
+            states[1],
+            Digest::default(),
+            expiration_block_num,
+            ExecutionProof::new(StarkProof::new_dummy(), HashFunction::Blake3_192),
+        )
+        .add_input_notes(nullifiers.iter().copied())
+        .add_output_notes(notes.iter().cloned())
+        .build()
+        .unwrap()
+    }
+
+    fn digest(seed: u64) -> Digest {
+        Digest::new([Felt::new(seed), ONE, ZERO, ONE])
+    }
+
+    fn nullifier(seed: u64) -> Nullifier {
+        Nullifier::from(digest(seed))
+    }
+}
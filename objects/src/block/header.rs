@@ -198,6 +198,12 @@ mod mock {
         ACCOUNT_TREE_DEPTH, ZERO,
     };
 
+    /// Timestamp of the mock block with number 0.
+    const MOCK_TIMESTAMP_START: u32 = 1693348223;
+
+    /// Difference between the timestamps of two consecutive mock blocks.
+    const MOCK_TIMESTAMP_STEP: u32 = 10;
+
     impl BlockHeader {
         pub fn mock(
             block_num: u32,
@@ -227,6 +233,9 @@ mod mock {
             let note_root: Digest = note_root.unwrap_or(rand::rand_array().into());
             let batch_root: Digest = rand::rand_array().into();
             let proof_hash: Digest = rand::rand_array().into();
+            // the timestamp is derived from the block number so that tests can reason about
+            // time-based conditions
+            let timestamp: Felt = (MOCK_TIMESTAMP_START + block_num * MOCK_TIMESTAMP_STEP).into();
 
             BlockHeader::new(
                 prev_hash,
@@ -238,7 +247,7 @@ mod mock {
                 batch_root,
                 proof_hash,
                 ZERO,
-                rand::rand_value(),
+                timestamp,
             )
         }
     }
@@ -1,5 +1,7 @@
 use super::{Digest, Felt, Hasher, ZERO};
 
+mod builder;
+pub use builder::BlockBuilder;
 mod header;
 pub use header::BlockHeader;
 mod note_tree;
@@ -5,10 +5,11 @@ use miden_crypto::{
     merkle::{LeafIndex, MerkleError, MerklePath, SimpleSmt},
 };
 
+use super::BlockHeader;
 use crate::{
-    notes::NoteMetadata,
+    notes::{NoteId, NoteInclusionProof, NoteMetadata},
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    BLOCK_OUTPUT_NOTES_TREE_DEPTH, MAX_NOTES_PER_BATCH,
+    NoteError, Word, BLOCK_OUTPUT_NOTES_TREE_DEPTH, MAX_NOTES_PER_BATCH,
 };
 
 /// Wrapper over [SimpleSmt<BLOCK_OUTPUT_NOTES_TREE_DEPTH>] for notes tree.
@@ -66,6 +67,46 @@ impl BlockNoteTree {
         Ok(note_path.into())
     }
 
+    /// Returns the inclusion proof of the note with the specified ID in the block with the
+    /// specified header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note root of the block header is not the root of this tree.
+    /// - The note with the specified ID is not in this tree.
+    pub fn get_note_inclusion_proof(
+        &self,
+        block_header: &BlockHeader,
+        note_id: NoteId,
+    ) -> Result<NoteInclusionProof, NoteError> {
+        if block_header.note_root() != self.root() {
+            return Err(NoteError::InconsistentNoteRoot(block_header.note_root(), self.root()));
+        }
+
+        // note IDs are stored at even leaf indexes, and metadata hashes at odd ones
+        let note_id_word = Word::from(note_id);
+        let note_index = self
+            .0
+            .leaves()
+            .find(|(index, leaf)| index % 2 == 0 && **leaf == note_id_word)
+            .map(|(index, _)| index / 2)
+            .ok_or(NoteError::NoteNotInBlock(note_id, block_header.block_num()))?;
+
+        // get the path to the note ID leaf and remove the first node, so that the path points to
+        // the parent of both the note ID and note metadata leaves
+        let leaf_index = LeafIndex::new(note_index * 2)
+            .map_err(|err| NoteError::invalid_origin_index(err.to_string()))?;
+        let note_path = self.0.open(&leaf_index).path[1..].to_vec();
+
+        NoteInclusionProof::new(
+            block_header.block_num(),
+            block_header.sub_hash(),
+            block_header.note_root(),
+            note_index,
+            note_path.into(),
+        )
+    }
+
     /// Returns an index to the node which the parent of both the note and note metadata.
     pub fn note_index(batch_idx: usize, note_idx_in_batch: usize) -> u64 {
         (batch_idx * MAX_NOTES_PER_BATCH + note_idx_in_batch) as u64
@@ -115,6 +156,49 @@ mod tests {
     };
 
     use super::BlockNoteTree;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_OFF_CHAIN_SENDER},
+        notes::{NoteId, NoteMetadata, NoteType},
+        BlockHeader, Digest, NoteError,
+    };
+
+    #[test]
+    fn test_note_inclusion_proof() {
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::OffChain, 0.into(), ZERO).unwrap();
+        let note_ids: [NoteId; 3] = [1, 2, 3].map(|seed| [Felt::new(seed), ONE, ZERO, ONE].into());
+
+        let tree = BlockNoteTree::with_entries([
+            (0, 0, (note_ids[0].into(), metadata)),
+            (0, 1, (note_ids[1].into(), metadata)),
+            (3, 7, (note_ids[2].into(), metadata)),
+        ])
+        .unwrap();
+        let header = build_header(5, tree.root());
+
+        for note_id in note_ids {
+            let proof = tree.get_note_inclusion_proof(&header, note_id).unwrap();
+            assert_eq!(proof.origin().block_num, 5);
+            assert!(proof.verify(note_id, &metadata, &header));
+        }
+
+        // the proof does not verify for a different note or a different block
+        let proof = tree.get_note_inclusion_proof(&header, note_ids[2]).unwrap();
+        assert_eq!(proof.origin().node_index.value(), BlockNoteTree::note_index(3, 7));
+        assert!(!proof.verify(note_ids[1], &metadata, &header));
+        assert!(!proof.verify(note_ids[2], &metadata, &build_header(6, tree.root())));
+
+        // proofs can not be generated for unknown notes or mismatched headers
+        let unknown_note: NoteId = [Felt::new(4), ONE, ZERO, ONE].into();
+        assert_eq!(
+            tree.get_note_inclusion_proof(&header, unknown_note),
+            Err(NoteError::NoteNotInBlock(unknown_note, 5))
+        );
+        assert!(matches!(
+            tree.get_note_inclusion_proof(&build_header(5, Digest::default()), note_ids[0]),
+            Err(NoteError::InconsistentNoteRoot(..))
+        ));
+    }
 
     #[test]
     fn test_serialization() {
@@ -129,4 +213,12 @@ mod tests {
 
         assert_eq!(deserialized_tree, initial_tree);
     }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn build_header(block_num: u32, note_root: Digest) -> BlockHeader {
+        let root = Digest::default();
+        BlockHeader::new(root, block_num, root, root, root, note_root, root, root, ZERO, ZERO)
+    }
 }
@@ -38,7 +38,12 @@ pub const BATCH_OUTPUT_NOTES_TREE_DEPTH: u8 = 13;
 pub const MAX_NOTES_PER_BATCH: usize = 2_usize.pow((BATCH_OUTPUT_NOTES_TREE_DEPTH - 1) as u32);
 
 /// The maximum number of transaction in a single batch.
-pub const MAX_TRANSACTIONS_PER_BATCH: usize = MAX_NOTES_PER_BATCH / MAX_OUTPUT_NOTES_PER_TX;
+///
+/// The total number of notes created by the transactions in a batch is limited separately by
+/// [MAX_NOTES_PER_BATCH], and thus the number of transactions is not derived from the number of
+/// notes a single transaction can create. Instead, it is set so that each transaction in a full
+/// batch can create at least one note.
+pub const MAX_TRANSACTIONS_PER_BATCH: usize = MAX_NOTES_PER_BATCH;
 
 // BLOCK
 // ================================================================================================
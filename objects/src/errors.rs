@@ -8,7 +8,8 @@ use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
+    notes::{NoteId, Nullifier},
+    transaction::TransactionId,
     Digest, Word,
 };
 use crate::{accounts::AccountType, notes::NoteType};
@@ -24,15 +25,28 @@ pub enum AccountError {
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes(u32, u32),
     AssetVaultUpdateError(AssetVaultError),
+    DuplicateStorageArrayElements(u64),
     DuplicateStorageItems(MerkleError),
+    DuplicateStorageMapKeys(MerkleError),
     FungibleFaucetIdInvalidFirstBit,
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
+    InvalidAccountDelta(AccountDeltaError),
     InvalidAccountStorageType,
+    MultisigWalletInvalidConfig(String),
     NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
     SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
+    StorageArrayDepthMismatch { slot: u8, expected: u8, actual: u8 },
+    StorageArrayIndexOutOfBounds(u8, u64),
+    StorageArrayInvalidDepth(u8),
+    StorageArrayNotFound(u8),
+    StorageArrayRootMismatch(u8),
+    StorageMapNotFound(u8),
+    StorageMapRootMismatch(u8),
     StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
     StorageSlotIsReserved(u8),
+    StorageSlotNotArraySlot(u8, StorageSlotType),
+    StorageSlotNotMapSlot(u8, StorageSlotType),
     StorageSlotNotValueSlot(u8, StorageSlotType),
     StubDataIncorrectLength(usize, usize),
 }
@@ -69,14 +83,22 @@ impl std::error::Error for AccountError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountDeltaError {
+    DuplicateStorageArrayElement(u64),
     DuplicateStorageItemUpdate(usize),
+    DuplicateStorageMapLeaf(Word),
     DuplicateVaultUpdate(Asset),
+    FungibleAssetDeltaTooBig(AccountId),
     InconsistentNonceUpdate(String),
     ImmutableStorageSlot(usize),
+    NotAFungibleFaucetId(AccountId),
     TooManyAddedAsset { actual: usize, max: usize },
     TooManyClearedStorageItems { actual: usize, max: usize },
+    TooManyFungibleAssets { actual: usize, max: usize },
     TooManyRemovedAssets { actual: usize, max: usize },
+    TooManyUpdatedStorageArrays { actual: usize, max: usize },
     TooManyUpdatedStorageItems { actual: usize, max: usize },
+    TooManyUpdatedStorageMaps { actual: usize, max: usize },
+    ZeroFungibleAssetDelta(AccountId),
 }
 
 #[cfg(feature = "std")]
@@ -194,17 +216,28 @@ pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     EmptyAssetList,
+    InconsistentNoteRoot(Digest, Digest),
     InconsistentNoteTag(NoteType, u64),
     InconsistentStubAssetHash(Digest, Digest),
     InconsistentStubId(NoteId, NoteId),
+    InvalidAccountIdInput(AccountError),
     InvalidAssetData(AssetError),
     InvalidOriginIndex(String),
+    InvalidPartialSwapAmounts { offered: u64, requested: u64 },
+    InvalidPartialSwapFillAmount { fill: u64, requested: u64 },
     InvalidStubDataLen(usize),
+    InvalidNoteInputsLen { expected: usize, actual: usize },
+    InvalidNoteTimestamp(u64),
     InvalidNoteSender(AccountError),
+    InvalidNoteTagValue(u64),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
+    InvalidRecallHeight(u64),
     NetworkExecutionRequiresOnChainAccount,
     NoteDeserializationError(DeserializationError),
+    NoteNotInBlock(NoteId, u32),
+    NotPartialSwapNote(NoteId),
+    PartialSwapAssetNotFungible(Asset),
     ScriptCompilationError(AssemblyError),
     TooManyAssets(usize),
     TooManyInputs(usize),
@@ -327,6 +360,7 @@ impl std::error::Error for TransactionInputError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionOutputError {
     DuplicateOutputNote(NoteId),
+    ExpirationBlockNumberInvalid(u64),
     FinalAccountDataNotFound,
     FinalAccountStubDataInvalid(AccountError),
     OutputNoteDataNotFound,
@@ -400,3 +434,78 @@ impl fmt::Display for ProvenTransactionError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for ProvenTransactionError {}
+
+// TRANSACTION BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionBatchError {
+    AccountDetailsMergeFailed {
+        account_id: AccountId,
+        error: AccountError,
+    },
+    AccountUpdateConflict {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    DuplicateNullifier(Nullifier),
+    DuplicateOutputNote(NoteId),
+    ExpiredTransaction {
+        tx_id: TransactionId,
+        expiration_block_num: u32,
+        block_num: u32,
+    },
+    InconsistentAccountDetails(AccountId),
+    NotesTreeError(MerkleError),
+    TooManyNotes {
+        max: usize,
+        actual: usize,
+    },
+    TooManyTransactions {
+        max: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for TransactionBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionBatchError {}
+
+// BLOCK ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockError {
+    AccountUpdateConflict {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    DuplicateNullifier(Nullifier),
+    ExpiredBatch {
+        batch_id: Digest,
+        expiration_block_num: u32,
+        block_num: u32,
+    },
+    NotesTreeError(MerkleError),
+    TooManyBatches {
+        max: usize,
+        actual: usize,
+    },
+    TransactionsInGenesisBlock,
+}
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockError {}
@@ -102,6 +102,44 @@ impl NoteAssets {
 
         padded_assets
     }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided asset to this list of note assets.
+    ///
+    /// If the list already contains a fungible asset issued by the same faucet, the amounts are
+    /// added together; otherwise the asset is appended to the end of the list. This mirrors the
+    /// way the transaction kernel adds assets to output notes, and thus results in the same
+    /// commitment.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The list already contains the same non-fungible asset.
+    /// - The total amount of a fungible asset would exceed the maximum allowed amount.
+    /// - The list already contains 256 assets.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), NoteError> {
+        match self.assets.iter_mut().find(|a| a.is_same(&asset)) {
+            Some(Asset::Fungible(current)) => {
+                let Asset::Fungible(asset) = asset else {
+                    unreachable!("only fungible assets can be the same as a fungible asset")
+                };
+                *current = current.add(asset).map_err(NoteError::InvalidAssetData)?;
+            },
+            Some(Asset::NonFungible(current)) => {
+                return Err(NoteError::duplicate_non_fungible_asset(*current));
+            },
+            None => {
+                if self.assets.len() >= Self::MAX_NUM_ASSETS {
+                    return Err(NoteError::too_many_assets(self.assets.len() + 1));
+                }
+                self.assets.push(asset);
+            },
+        }
+
+        self.hash = compute_asset_commitment(&self.assets);
+        Ok(())
+    }
 }
 
 impl PartialEq for NoteAssets {
@@ -163,3 +201,53 @@ impl Deserializable for NoteAssets {
         Self::new(assets).map_err(|e| DeserializationError::InvalidValue(format!("{e:?}")))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{compute_asset_commitment, NoteAssets};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    };
+
+    #[test]
+    fn add_asset_to_note_assets() {
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let faucet_3 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let asset_1: Asset = FungibleAsset::new(faucet_1, 100).unwrap().into();
+        let asset_2: Asset = FungibleAsset::new(faucet_2, 50).unwrap().into();
+        let asset_3: Asset =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_3, vec![1, 2, 3]).unwrap())
+                .unwrap()
+                .into();
+
+        let mut assets = NoteAssets::new(vec![asset_1]).unwrap();
+        assets.add_asset(asset_2).unwrap();
+        assets.add_asset(asset_3).unwrap();
+
+        // adding a fungible asset from the same faucet merges it with the existing one
+        assets.add_asset(FungibleAsset::new(faucet_1, 25).unwrap().into()).unwrap();
+
+        let expected = [FungibleAsset::new(faucet_1, 125).unwrap().into(), asset_2, asset_3];
+        assert_eq!(assets.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(assets.commitment(), compute_asset_commitment(&expected));
+
+        // adding the same non-fungible asset twice is not allowed
+        assert!(assets.add_asset(asset_3).is_err());
+
+        // exceeding the maximum amount of a fungible asset is not allowed
+        let max_asset = FungibleAsset::new(faucet_2, FungibleAsset::MAX_AMOUNT).unwrap();
+        assert!(assets.add_asset(max_asset.into()).is_err());
+        assert_eq!(assets.commitment(), compute_asset_commitment(&expected));
+    }
+}
@@ -0,0 +1,114 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteAssets, NoteId,
+    NoteInputs, NoteRecipient, NoteScript, Nullifier, Serializable, Word,
+};
+
+// NOTE DETAILS
+// ================================================================================================
+
+/// Details of a note consisting of its assets and recipient.
+///
+/// Unlike a [Note], note details do not include the note's metadata. This makes it possible to
+/// describe a note before the account which will create it (and thus the note's sender) is known,
+/// e.g., the payback note of a SWAP note, which is created by the account consuming the SWAP note.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoteDetails {
+    assets: NoteAssets,
+    recipient: NoteRecipient,
+}
+
+impl NoteDetails {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new note details created with the specified parameters.
+    pub fn new(assets: NoteAssets, recipient: NoteRecipient) -> Self {
+        Self { assets, recipient }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note's assets.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+
+    /// Returns the note's recipient.
+    pub fn recipient(&self) -> &NoteRecipient {
+        &self.recipient
+    }
+
+    /// Returns the note's unique identifier.
+    pub fn id(&self) -> NoteId {
+        NoteId::new(self.recipient.digest(), self.assets.commitment())
+    }
+
+    /// Returns the note's nullifier.
+    pub fn nullifier(&self) -> Nullifier {
+        Nullifier::new(
+            self.recipient.script().hash(),
+            self.recipient.inputs().commitment(),
+            self.assets.commitment(),
+            self.recipient.serial_num(),
+        )
+    }
+
+    /// Returns the note's recipient serial_num, the secret required to consume the note.
+    pub fn serial_num(&self) -> Word {
+        self.recipient.serial_num()
+    }
+
+    /// Returns the note's recipient script which locks the assets of this note.
+    pub fn script(&self) -> &NoteScript {
+        self.recipient.script()
+    }
+
+    /// Returns the note's recipient inputs which customizes the script's behavior.
+    pub fn inputs(&self) -> &NoteInputs {
+        self.recipient.inputs()
+    }
+
+    // DATA EXTRACTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Decomposes these note details into their assets and recipient.
+    pub fn into_parts(self) -> (NoteAssets, NoteRecipient) {
+        (self.assets, self.recipient)
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<Note> for NoteDetails {
+    fn from(note: Note) -> Self {
+        (&note).into()
+    }
+}
+
+impl From<&Note> for NoteDetails {
+    fn from(note: &Note) -> Self {
+        Self::new(note.assets().clone(), note.recipient().clone())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.assets.write_into(target);
+        self.recipient.write_into(target);
+    }
+}
+
+impl Deserializable for NoteDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let assets = NoteAssets::read_from(source)?;
+        let recipient = NoteRecipient::read_from(source)?;
+
+        Ok(Self::new(assets, recipient))
+    }
+}
@@ -15,6 +15,9 @@ use crate::{
 mod assets;
 pub use assets::NoteAssets;
 
+mod details;
+pub use details::NoteDetails;
+
 mod envelope;
 pub use envelope::NoteEnvelope;
 
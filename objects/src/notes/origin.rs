@@ -1,10 +1,13 @@
 use alloc::string::ToString;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteError, Serializable,
-    NOTE_TREE_DEPTH,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteError,
+    NoteId, NoteMetadata, Serializable, Word, NOTE_TREE_DEPTH,
+};
+use crate::{
+    crypto::merkle::{MerklePath, NodeIndex},
+    BlockHeader,
 };
-use crate::crypto::merkle::{MerklePath, NodeIndex};
 
 /// Contains information about the origin of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -76,6 +79,29 @@ impl NoteInclusionProof {
     pub fn note_path(&self) -> &MerklePath {
         &self.note_path
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this proof attests that the note with the specified ID and metadata was
+    /// created in the block with the specified header.
+    pub fn verify(
+        &self,
+        note_id: NoteId,
+        note_metadata: &NoteMetadata,
+        block_header: &BlockHeader,
+    ) -> bool {
+        if self.origin.block_num != block_header.block_num()
+            || self.sub_hash != block_header.sub_hash()
+            || self.note_root != block_header.note_root()
+        {
+            return false;
+        }
+
+        let note_hash = Hasher::merge(&[note_id.inner(), Word::from(note_metadata).into()]);
+        self.note_path
+            .verify(self.origin.node_index.value(), note_hash, &block_header.note_root())
+    }
 }
 
 // SERIALIZATION
@@ -74,7 +74,7 @@ impl From<&NoteScript> for Vec<Felt> {
         let len = bytes.len();
 
         // Pad the data so that it can be encoded with u32
-        let missing = len % 4;
+        let missing = (4 - len % 4) % 4;
         bytes.resize(bytes.len() + missing, 0);
 
         let final_size = 5 + bytes.len();
@@ -125,7 +125,7 @@ impl TryFrom<&[Felt]> for NoteScript {
                 .map_err(|v| DeserializationError::InvalidValue(format!("{v}")))?;
             data.extend(v.to_le_bytes())
         }
-        data.shrink_to(len as usize);
+        data.truncate(len as usize);
 
         // TODO: validate the hash matches the code
         let code = ProgramAst::from_bytes(&data)?;
@@ -159,3 +159,29 @@ impl Deserializable for NoteScript {
         Ok(Self::from_parts(code, hash))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use super::{Assembler, Felt, NoteScript, ProgramAst};
+
+    #[test]
+    fn test_note_script_to_from_felt() {
+        let assembler = Assembler::default();
+
+        // scripts of different lengths cover all possible paddings of the encoded bytes
+        for num_ops in 1..=4 {
+            let source = format!("begin {} end", String::from("push.1 drop ").repeat(num_ops));
+            let code = ProgramAst::parse(&source).unwrap();
+            let (note_script, _) = NoteScript::new(code, &assembler).unwrap();
+
+            let encoded: Vec<Felt> = (&note_script).into();
+            let decoded = NoteScript::try_from(encoded).unwrap();
+            assert_eq!(note_script, decoded);
+        }
+    }
+}
@@ -1,8 +1,10 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use core::num::NonZeroUsize;
 
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
-    BlockHeader, ChainMmrError,
+    crypto::merkle::{InOrderIndex, InnerNodeInfo, MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader, ChainMmrError, Digest,
 };
 
 // CHAIN MMR
@@ -113,12 +115,56 @@ impl ChainMmr {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ChainMmr {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let forest = self.mmr.forest();
+        forest.write_into(target);
+        self.mmr.peaks().peaks().to_vec().write_into(target);
+
+        let nodes: Vec<(u64, Digest)> =
+            self.mmr.nodes().map(|(index, node)| (index.inner(), *node)).collect();
+        nodes.write_into(target);
+
+        // the latest leaf is tracked separately from the nodes in an MMR with an odd forest
+        let track_latest = forest & 1 != 0 && self.mmr.is_tracked(forest - 1);
+        target.write_bool(track_latest);
+
+        self.blocks.values().cloned().collect::<Vec<_>>().write_into(target);
+    }
+}
+
+impl Deserializable for ChainMmr {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let forest = usize::read_from(source)?;
+        let peaks = MmrPeaks::new(forest, Vec::<Digest>::read_from(source)?)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let nodes = Vec::<(u64, Digest)>::read_from(source)?
+            .into_iter()
+            .map(|(index, node)| {
+                NonZeroUsize::new(index as usize)
+                    .map(|index| (InOrderIndex::new(index), node))
+                    .ok_or(DeserializationError::InvalidValue("zero MMR node index".to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        let track_latest = source.read_bool()?;
+        let mmr = PartialMmr::from_parts(peaks, nodes, track_latest);
+
+        let blocks = Vec::<BlockHeader>::read_from(source)?;
+
+        Self::new(mmr, blocks).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::ChainMmr;
+    use super::{ChainMmr, Deserializable, Serializable};
     use crate::{
         alloc::vec::Vec,
         crypto::merkle::{Mmr, PartialMmr},
@@ -170,6 +216,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chain_mmr_serialization() {
+        // create chain MMR with 3 blocks and track the latest two blocks added to it, the latest
+        // of which is a single leaf tree
+        let mut mmr = Mmr::default();
+        for i in 0..3 {
+            mmr.add(int_to_block_header(i).hash());
+        }
+        let partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+        chain_mmr.add_block(int_to_block_header(3), true);
+        chain_mmr.add_block(int_to_block_header(4), true);
+
+        let bytes = chain_mmr.to_bytes();
+        let deserialized = ChainMmr::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, chain_mmr);
+        assert!(deserialized.mmr.is_tracked(4));
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
         BlockHeader::new(
             Digest::default(),
@@ -19,6 +19,10 @@ use super::{
 ///   stateless manner. This includes all public transaction inputs, but also all nondeterministic
 ///   inputs that the host provided to Miden VM while executing the transaction (i.e., advice
 ///   witness).
+///
+/// Executed transaction does not support serialization as the compiled transaction program
+/// cannot be serialized. To transfer a transaction to a remote prover, it should be converted
+/// into a [TransactionWitness], which can be serialized and proven without the program.
 #[derive(Debug, Clone)]
 pub struct ExecutedTransaction {
     id: OnceCell<TransactionId>,
@@ -98,6 +102,11 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the number of the block at which this transaction expires.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.tx_outputs.expiration_block_num
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -129,12 +138,8 @@ impl ExecutedTransaction {
 
     /// Returns individual components of this transaction.
     pub fn into_parts(self) -> (AccountDelta, TransactionOutputs, TransactionWitness) {
-        let tx_witness = TransactionWitness::new(
-            self.program,
-            self.tx_inputs,
-            self.tx_args,
-            self.advice_witness,
-        );
+        let tx_witness = TransactionWitness::new(self.tx_inputs, self.tx_args, self.advice_witness)
+            .with_program(self.program);
 
         (self.account_delta, self.tx_outputs, tx_witness)
     }
@@ -18,6 +18,7 @@ use crate::{
 pub struct TransactionOutputs {
     pub account: AccountStub,
     pub output_notes: OutputNotes,
+    pub expiration_block_num: u32,
 }
 
 // OUTPUT NOTES
@@ -55,6 +55,9 @@ pub struct ProvenTransaction {
     /// The block hash of the last known block at the time the transaction was executed.
     block_ref: Digest,
 
+    /// The number of the block at which the transaction expires.
+    expiration_block_num: u32,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -113,6 +116,11 @@ impl ProvenTransaction {
         self.block_ref
     }
 
+    /// Returns the number of the block at which the transaction expires.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -200,6 +208,9 @@ pub struct ProvenTransactionBuilder {
     /// Block [Digest] of the transaction's reference block.
     block_ref: Digest,
 
+    /// The number of the block at which the transaction expires.
+    expiration_block_num: u32,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -214,6 +225,7 @@ impl ProvenTransactionBuilder {
         initial_account_hash: Digest,
         final_account_hash: Digest,
         block_ref: Digest,
+        expiration_block_num: u32,
         proof: ExecutionProof,
     ) -> Self {
         Self {
@@ -225,6 +237,7 @@ impl ProvenTransactionBuilder {
             output_notes: Vec::new(),
             tx_script_root: None,
             block_ref,
+            expiration_block_num,
             proof,
         }
     }
@@ -292,6 +305,7 @@ impl ProvenTransactionBuilder {
             output_notes,
             tx_script_root,
             block_ref: self.block_ref,
+            expiration_block_num: self.expiration_block_num,
             proof: self.proof,
         };
 
@@ -339,6 +353,7 @@ impl Serializable for ProvenTransaction {
         self.output_notes.write_into(target);
         self.tx_script_root.write_into(target);
         self.block_ref.write_into(target);
+        self.expiration_block_num.write_into(target);
         self.proof.write_into(target);
     }
 }
@@ -356,6 +371,7 @@ impl Deserializable for ProvenTransaction {
         let tx_script_root = Deserializable::read_from(source)?;
 
         let block_ref = Digest::read_from(source)?;
+        let expiration_block_num = u32::read_from(source)?;
         let proof = ExecutionProof::read_from(source)?;
 
         let id = TransactionId::new(
@@ -375,6 +391,7 @@ impl Deserializable for ProvenTransaction {
             output_notes,
             tx_script_root,
             block_ref,
+            expiration_block_num,
             proof,
         };
 
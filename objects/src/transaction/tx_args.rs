@@ -1,15 +1,22 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use vm_processor::AdviceMap;
 
 use super::{Digest, Felt, Word};
 use crate::{
-    assembly::{Assembler, AssemblyContext, ProgramAst},
-    notes::{Note, NoteId, NoteInputs},
+    assembly::{Assembler, AssemblyContext, AstSerdeOptions, ProgramAst},
+    notes::{NoteDetails, NoteId, NoteInputs},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     vm::CodeBlock,
     TransactionScriptError,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Default serialization options for transaction script code AST.
+const CODE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
+
 // TRANSACTION ARGS
 // ================================================================================================
 
@@ -67,7 +74,10 @@ impl TransactionArgs {
     // MODIFIERS
     // --------------------------------------------------------------------------------------------
 
-    /// Populates the advice inputs with the details of [Note]s.
+    /// Populates the advice inputs with the details of an expected output note.
+    ///
+    /// The note can be provided either as a [Note](crate::notes::Note) or as [NoteDetails], the
+    /// latter being useful when the metadata of the note is not known in advance.
     ///
     /// The map is extended with the following keys:
     ///
@@ -76,7 +86,8 @@ impl TransactionArgs {
     ///   adding ONE to its most significant element.
     /// - script_hash |-> script.
     ///
-    pub fn add_expected_output_note(&mut self, note: &Note) {
+    pub fn add_expected_output_note<T: Into<NoteDetails>>(&mut self, note: T) {
+        let note: NoteDetails = note.into();
         let recipient = note.recipient();
         let inputs = note.inputs();
         let script = note.script();
@@ -89,7 +100,7 @@ impl TransactionArgs {
         self.advice_map.insert(script.hash(), script_encoded);
     }
 
-    /// Populates the advice inputs with the details of [Note]s.
+    /// Populates the advice inputs with the details of expected output notes.
     ///
     /// The map is extended with the following keys:
     ///
@@ -98,12 +109,13 @@ impl TransactionArgs {
     ///   adding ONE to its most significant element.
     /// - script_hash |-> script
     ///
-    pub fn extend_expected_output_notes<T>(&mut self, notes: T)
+    pub fn extend_expected_output_notes<T, N>(&mut self, notes: T)
     where
-        T: IntoIterator<Item = Note>,
+        T: IntoIterator<Item = N>,
+        N: Into<NoteDetails>,
     {
         for note in notes {
-            self.add_expected_output_note(&note);
+            self.add_expected_output_note(note);
         }
     }
 
@@ -207,3 +219,61 @@ impl TransactionScript {
         &self.inputs
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionArgs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_script.write_into(target);
+        self.note_args.write_into(target);
+        write_advice_map(&self.advice_map, target);
+    }
+}
+
+impl Deserializable for TransactionArgs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_script = <Option<TransactionScript>>::read_from(source)?;
+        let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
+        let advice_map = read_advice_map(source)?;
+
+        // the advice map already contains the transaction script inputs, and so we do not use the
+        // constructor here
+        Ok(Self { tx_script, note_args, advice_map })
+    }
+}
+
+impl Serializable for TransactionScript {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.hash.write_into(target);
+        self.code.write_into(target, CODE_SERDE_OPTIONS);
+        self.inputs.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionScript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash = Digest::read_from(source)?;
+        let code = ProgramAst::read_from(source)?;
+        let inputs = BTreeMap::<Digest, Vec<Felt>>::read_from(source)?;
+
+        Self::from_parts(code, hash, inputs.into_iter().map(|(key, value)| (key.into(), value)))
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Serializes the provided advice map as a map from keys to the values stored under these keys.
+fn write_advice_map<W: ByteWriter>(advice_map: &AdviceMap, target: &mut W) {
+    let entries: BTreeMap<Digest, Vec<Felt>> = advice_map.clone().into_iter().collect();
+    entries.write_into(target);
+}
+
+/// Deserializes an advice map written by [write_advice_map()].
+pub(super) fn read_advice_map<R: ByteReader>(
+    source: &mut R,
+) -> Result<AdviceMap, DeserializationError> {
+    BTreeMap::<Digest, Vec<Felt>>::read_from(source).map(AdviceMap::from)
+}
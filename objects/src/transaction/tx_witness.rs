@@ -1,5 +1,14 @@
+use alloc::vec::Vec;
+
+use vm_processor::MemAdviceProvider;
+
 use super::{
-    Account, AdviceInputs, BlockHeader, InputNotes, Program, TransactionArgs, TransactionInputs,
+    tx_args::read_advice_map, Account, AdviceInputs, BlockHeader, Digest, Felt, Hasher, InputNotes,
+    Program, TransactionArgs, TransactionInputs,
+};
+use crate::{
+    crypto::merkle::{InnerNodeInfo, MerkleStore},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 // TRANSACTION WITNESS
@@ -12,21 +21,26 @@ use super::{
 /// of transactions.
 ///
 /// A transaction witness consists of:
-/// - The executable transaction [Program].
 /// - Transaction inputs which contain information about the initial state of the account, input
 ///   notes, block header etc.
 /// - An optional transaction script.
 /// - Advice witness which contains all data requested by the VM from the advice provider while
 ///   executing the transaction program.
 ///
+/// The executable transaction program is not serialized with the witness as it can be rebuilt
+/// from the account code, the note scripts, and the transaction script contained in the witness.
+/// However, a witness obtained from an [ExecutedTransaction](super::ExecutedTransaction) retains
+/// the program which was executed so that it does not need to be compiled again for proving.
+///
 /// TODO: currently, the advice witness contains redundant and irrelevant data (e.g., tx inputs
 /// and tx outputs). we should optimize it to contain only the minimum data required for
 /// executing/proving the transaction.
+#[derive(Debug, Clone)]
 pub struct TransactionWitness {
-    program: Program,
     tx_inputs: TransactionInputs,
     tx_args: TransactionArgs,
     advice_witness: AdviceInputs,
+    program: Option<Program>,
 }
 
 impl TransactionWitness {
@@ -34,27 +48,27 @@ impl TransactionWitness {
     // --------------------------------------------------------------------------------------------
     /// Creates a new [TransactionWitness] from the provided data.
     pub fn new(
-        program: Program,
         tx_inputs: TransactionInputs,
         tx_args: TransactionArgs,
         advice_witness: AdviceInputs,
     ) -> Self {
         Self {
-            program,
             tx_inputs,
             tx_args,
             advice_witness,
+            program: None,
         }
     }
 
+    /// Sets the program which was executed to produce this witness.
+    pub(super) fn with_program(mut self, program: Program) -> Self {
+        self.program = Some(program);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a reference the program defining this transaction.
-    pub fn program(&self) -> &Program {
-        &self.program
-    }
-
     /// Returns the account state before the transaction was executed.
     pub fn account(&self) -> &Account {
         self.tx_inputs.account()
@@ -85,4 +99,61 @@ impl TransactionWitness {
     pub fn advice_witness(&self) -> &AdviceInputs {
         &self.advice_witness
     }
+
+    /// Returns the executed transaction program, if it is retained by this witness.
+    ///
+    /// The program is available only for witnesses built from an
+    /// [ExecutedTransaction](super::ExecutedTransaction), and is not preserved through
+    /// serialization.
+    pub fn program(&self) -> Option<&Program> {
+        self.program.as_ref()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.tx_args.write_into(target);
+
+        self.advice_witness.stack().to_vec().write_into(target);
+
+        // the advice map is not exposed by the advice inputs directly, and thus it is extracted
+        // via the advice provider
+        let (_, advice_map, _) = MemAdviceProvider::from(self.advice_witness.clone()).into_parts();
+        advice_map.write_into(target);
+
+        // inner node values are not serialized as they can be recomputed from their children
+        let nodes: Vec<(Digest, Digest)> = self
+            .advice_witness
+            .merkle_store()
+            .inner_nodes()
+            .map(|node| (node.left, node.right))
+            .collect();
+        nodes.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+
+        let stack = Vec::<Felt>::read_from(source)?;
+        let map = read_advice_map(source)?;
+        let mut store = MerkleStore::new();
+        store.extend(Vec::<(Digest, Digest)>::read_from(source)?.into_iter().map(
+            |(left, right)| InnerNodeInfo {
+                value: Hasher::merge(&[left, right]),
+                left,
+                right,
+            },
+        ));
+        let advice_witness =
+            AdviceInputs::default().with_stack(stack).with_map(map).with_merkle_store(store);
+
+        Ok(Self::new(tx_inputs, tx_args, advice_witness))
+    }
 }